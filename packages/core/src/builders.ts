@@ -10,7 +10,8 @@ import {
     LockCreationResult,
     validateAmount, 
     validateTimestamp, 
-    findTimeLockPDA 
+    findTimeLockPDA,
+    findConfigPDA
 } from "./types";
 
 /**
@@ -23,6 +24,11 @@ export class TimeLockBuilders {
         this.program = program;
     }
 
+    private async configPDA(): Promise<PublicKey> {
+        const [config] = await findConfigPDA(this.program.programId);
+        return config;
+    }
+
     // ========================================================================
     // SOL OPERATIONS
     // ========================================================================
@@ -44,6 +50,9 @@ export class TimeLockBuilders {
             .accounts({
                 timeLockAccount: timeLockAccount,
                 initializer: params.owner,
+                beneficiary: params.beneficiary ?? null,
+                mint: null,
+                config: await this.configPDA(),
                 systemProgram: SystemProgram.programId,
             })
             .instruction();
@@ -64,7 +73,9 @@ export class TimeLockBuilders {
             .depositSol(new anchor.BN(params.amount))
             .accounts({
                 timeLockAccount: params.timeLockAccount,
-                initializer: signerKey,
+                depositor: signerKey,
+                config: await this.configPDA(),
+                treasury: params.treasury ?? null,
                 systemProgram: SystemProgram.programId,
             })
             .instruction();
@@ -78,7 +89,9 @@ export class TimeLockBuilders {
             .withdrawSol()
             .accounts({
                 timeLockAccount: params.timeLockAccount,
-                owner: params.owner,
+                beneficiary: params.beneficiary ?? params.owner,
+                config: await this.configPDA(),
+                treasury: params.treasury ?? null,
                 systemProgram: SystemProgram.programId,
             })
             .instruction();
@@ -93,6 +106,9 @@ export class TimeLockBuilders {
      */
     async buildTokenInitialize(params: CreateTimeLockParams): Promise<TransactionInstruction> {
         validateTimestamp(params.unlockTimestamp);
+        if (!params.tokenMint) {
+            throw new Error("Token mint is required for token time locks");
+        }
 
         const [timeLockAccount] = await findTimeLockPDA(
             params.owner, 
@@ -105,6 +121,9 @@ export class TimeLockBuilders {
             .accounts({
                 timeLockAccount: timeLockAccount,
                 initializer: params.owner,
+                beneficiary: params.beneficiary ?? null,
+                mint: params.tokenMint,
+                config: await this.configPDA(),
                 systemProgram: SystemProgram.programId,
             })
             .instruction();
@@ -125,10 +144,13 @@ export class TimeLockBuilders {
             .depositToken(new anchor.BN(params.amount))
             .accounts({
                 timeLockAccount: params.timeLockAccount,
-                initializer: signerKey,
+                depositor: signerKey,
                 mint: params.tokenMint,
                 tokenFromAta: params.tokenFromAta,
                 tokenVault: params.tokenVault,
+                config: await this.configPDA(),
+                treasury: params.treasury ?? null,
+                treasuryTokenAccount: params.treasuryTokenAccount ?? null,
                 tokenProgram: params.tokenProgramId,
                 associatedTokenProgram: params.associatedTokenProgramId,
                 systemProgram: SystemProgram.programId,
@@ -144,10 +166,16 @@ export class TimeLockBuilders {
             .withdrawToken()
             .accounts({
                 timeLockAccount: params.timeLockAccount,
+                beneficiary: params.beneficiary ?? params.owner,
                 owner: params.owner,
                 tokenFromVault: params.tokenFromVault,
                 tokenToAta: params.tokenToAta,
+                mint: params.tokenMint,
+                config: await this.configPDA(),
+                treasury: params.treasury ?? null,
+                treasuryTokenAccount: params.treasuryTokenAccount ?? null,
                 tokenProgram: params.tokenProgramId,
+                systemProgram: SystemProgram.programId,
             })
             .instruction();
     }
@@ -197,7 +225,10 @@ export class TimeLockBuilders {
             this.program.programId
         );
 
-        const initInstruction = await this.buildTokenInitialize(params);
+        const initInstruction = await this.buildTokenInitialize({
+            ...params,
+            tokenMint: params.tokenMint ?? depositParams.tokenMint
+        });
         const depositInstruction = await this.buildTokenDeposit({
             ...depositParams,
             timeLockAccount
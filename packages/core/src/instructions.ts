@@ -284,10 +284,12 @@ export class TimeLockInstructions {
         try {
             params.timeLockAccount = this.normalizePublicKey(params.timeLockAccount);
             params.owner = this.normalizePublicKey(params.owner);
+            params.tokenMint = this.normalizePublicKey(params.tokenMint);
             params.tokenFromVault = this.normalizePublicKey(params.tokenFromVault);
             params.tokenToAta = this.normalizePublicKey(params.tokenToAta);
             validatePublicKey(params.timeLockAccount);
             validatePublicKey(params.owner);
+            validatePublicKey(params.tokenMint);
             validatePublicKey(params.tokenFromVault);
             validatePublicKey(params.tokenToAta);
 
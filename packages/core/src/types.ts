@@ -41,6 +41,10 @@ export interface CreateTimeLockParams {
     unlockTimestamp: number;
     assetType: AssetType;
     amount?: number;
+    /** Key allowed to withdraw, defaults to the owner */
+    beneficiary?: PublicKey;
+    /** Mint of a token lock, fixed at creation */
+    tokenMint?: PublicKey;
 }
 
 /**
@@ -50,6 +54,8 @@ export interface DepositParams {
     timeLockAccount: PublicKey;
     amount: number;
     depositor?: PublicKey;
+    /** Protocol treasury, required while the program config charges fees */
+    treasury?: PublicKey;
 }
 
 /**
@@ -61,6 +67,8 @@ export interface TokenDepositParams extends DepositParams {
     tokenVault: PublicKey;
     tokenProgramId: PublicKey;
     associatedTokenProgramId: PublicKey;
+    /** Treasury token account for the mint, required while a deposit fee applies */
+    treasuryTokenAccount?: PublicKey;
 }
 
 /**
@@ -69,15 +77,22 @@ export interface TokenDepositParams extends DepositParams {
 export interface WithdrawParams {
     timeLockAccount: PublicKey;
     owner: PublicKey;
+    /** Key holding the withdrawal rights, defaults to the owner */
+    beneficiary?: PublicKey;
+    /** Protocol treasury, required while the program config charges fees */
+    treasury?: PublicKey;
 }
 
 /**
  * Parameters for token withdrawals
  */
 export interface TokenWithdrawParams extends WithdrawParams {
+    tokenMint: PublicKey;
     tokenFromVault: PublicKey;
     tokenToAta: PublicKey;
     tokenProgramId: PublicKey;
+    /** Treasury token account for the mint, required while a withdrawal fee applies */
+    treasuryTokenAccount?: PublicKey;
 }
 
 /**
@@ -111,6 +126,7 @@ export const PROGRAM_CONSTANTS = {
     PROGRAM_ID: new PublicKey("899SKikn1WiRBSurKhMZyNCNvYmWXVE6hZFYbFim293g"),
     SEEDS: {
         TIME_LOCK: "time_lock",
+        CONFIG: "config",
     },
     ACCOUNT_SIZE: {
         TIME_LOCK_ACCOUNT: 8 + 32 + 8 + 1 + 1 + 8 + 32, // 90 bytes
@@ -281,6 +297,18 @@ export async function findTimeLockPDA(
     );
 }
 
+/**
+ * Find the PDA of the program-wide config (fees, lock bounds, pause switch).
+ * @param programId The program ID.
+ * @returns A promise resolving to an array containing the PDA and its bump seed.
+ */
+export async function findConfigPDA(programId: PublicKey): Promise<[PublicKey, number]> {
+    return PublicKey.findProgramAddress(
+        [anchor.utils.bytes.utf8.encode("config")],
+        programId
+    );
+}
+
 // ========================================================================
 // Additional Required Exports
 // ========================================================================
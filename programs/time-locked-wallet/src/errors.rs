@@ -9,6 +9,36 @@ pub enum TimeLockError {
     #[msg("INVALID_UNLOCK_TIME: Unlock timestamp must be in the future")]
     InvalidUnlockTime = 1002,
     
+    #[msg("INVALID_VESTING_SCHEDULE: Vesting requires start <= cliff <= end and start < end")]
+    InvalidVestingSchedule = 1003,
+    
+    #[msg("INVALID_TRANCHE_SCHEDULE: Tranches must be non-empty, bounded, strictly increasing and non-zero")]
+    InvalidTrancheSchedule = 1004,
+    
+    #[msg("INVALID_LOCK_EXTENSION: Only single-cliff locks can be extended, and only to a later time")]
+    InvalidLockExtension = 1005,
+    
+    #[msg("RECOVERY_DELAY_NOT_ELAPSED: Recovery can only complete after the guardian delay")]
+    RecoveryDelayNotElapsed = 1006,
+    
+    #[msg("EARLY_UNLOCK_NOT_ALLOWED: This lock has no early unlock terms")]
+    EarlyUnlockNotAllowed = 1007,
+    
+    #[msg("INVALID_UNLOCK_CONDITION: Slot or epoch target must be in the future and the instruction must support it")]
+    InvalidUnlockCondition = 1008,
+    
+    #[msg("HASHLOCK_EXPIRED: The preimage deadline has passed, only the owner can reclaim")]
+    HashlockExpired = 1009,
+    
+    #[msg("HEARTBEAT_NOT_LAPSED: The owner checked in recently, heirs cannot claim yet")]
+    HeartbeatNotLapsed = 1010,
+    
+    #[msg("INVALID_HEARTBEAT_INTERVAL: Heartbeat interval must be positive")]
+    InvalidHeartbeatInterval = 1011,
+    
+    #[msg("LOCK_DURATION_OUT_OF_RANGE: Unlock time is outside the configured minimum and maximum lock duration")]
+    LockDurationOutOfRange = 1012,
+    
     // === BALANCE ERRORS (1100-1199) ===
     #[msg("INSUFFICIENT_BALANCE: Account balance too low for withdrawal")]
     InsufficientBalance = 1101,
@@ -22,17 +52,71 @@ pub enum TimeLockError {
     #[msg("ACCOUNT_NOT_EMPTY: Cannot close account with remaining funds")]
     AccountNotEmpty = 1104,
     
+    #[msg("NOTHING_TO_CLAIM: No vested funds are available to claim yet")]
+    NothingToClaim = 1105,
+    
+    #[msg("INVALID_PENALTY: Penalty must be at most 10000 bps with a penalty recipient")]
+    InvalidPenalty = 1106,
+    
     // === ASSET TYPE ERRORS (1200-1299) ===
     #[msg("INVALID_ASSET_TYPE: Operation not supported for this asset type")]
     InvalidAssetType = 1201,
     
-    #[msg("INVALID_TOKEN_VAULT: Token vault mismatch")]
+    #[msg("INVALID_TOKEN_VAULT: Token vault does not match the vault recorded for this lock")]
     InvalidTokenVault = 1202,
     
+    #[msg("INVALID_MINT: Token account mint does not match the locked mint")]
+    InvalidMint = 1203,
+    
+    #[msg("BASKET_FULL: This basket already holds the maximum number of mints")]
+    BasketFull = 1204,
+    
+    #[msg("UNSUPPORTED_BASKET_LOCK: Soft, hash-time and inheritance locks cannot hold a basket")]
+    UnsupportedBasketLock = 1205,
+    
     // === AUTHORIZATION ERRORS (1300-1399) ===
     #[msg("UNAUTHORIZED: Caller is not the account owner")]
     Unauthorized = 1301,
     
+    #[msg("NOT_REVOCABLE: This lock was not created as revocable")]
+    NotRevocable = 1302,
+    
+    #[msg("INSUFFICIENT_APPROVALS: Multisig approval threshold not met")]
+    InsufficientApprovals = 1303,
+    
+    #[msg("INVALID_MULTISIG_CONFIG: Signers must be unique, non-empty and bounded with 1 <= threshold <= signers")]
+    InvalidMultisigConfig = 1304,
+    
+    #[msg("INVALID_GUARDIAN_CONFIG: Guardians must be unique, non-empty and bounded with multisig threshold <= threshold <= guardians")]
+    InvalidGuardianConfig = 1305,
+    
+    #[msg("NO_GUARDIANS: This lock has no guardian set configured")]
+    NoGuardians = 1306,
+    
+    #[msg("INVALID_PENALTY_RECIPIENT: Penalty must be paid to the configured recipient")]
+    InvalidPenaltyRecipient = 1307,
+    
+    #[msg("INVALID_PREIMAGE: sha256 of the preimage does not match the stored hash")]
+    InvalidPreimage = 1308,
+    
+    #[msg("INVALID_HEIRS: Heirs must be unique, non-empty, bounded and split exactly 10000 bps")]
+    InvalidHeirs = 1309,
+    
+    #[msg("INVALID_CONFIG: Durations must be non-negative with min <= max, fees at most 1000 bps and a treasury set when fees apply")]
+    InvalidConfig = 1310,
+    
+    #[msg("INVALID_TREASURY: A fee is due and the configured treasury account was not provided")]
+    InvalidTreasury = 1311,
+    
+    #[msg("INVALID_NEW_OWNER: The new owner or beneficiary must be a non-default key different from the current one")]
+    InvalidNewOwner = 1312,
+    
+    #[msg("INVALID_RECIPIENT: Funds cannot be withdrawn back into the lock or its vault")]
+    InvalidRecipient = 1313,
+    
+    #[msg("SELF_GRANT_NOT_REVOCABLE: Only a grant to a beneficiary other than the owner can be revoked")]
+    SelfGrantNotRevocable = 1314,
+    
     // === SECURITY ERRORS (1400-1499) ===
     #[msg("REENTRANCY_DETECTED: Operation already in progress")]
     OperationInProgress = 1401,
@@ -41,6 +125,21 @@ pub enum TimeLockError {
     #[msg("NOT_INITIALIZED: Account not properly initialized")]
     NotInitialized = 1501,
     
+    #[msg("NO_PENDING_RECOVERY: There is no recovery in progress")]
+    NoPendingRecovery = 1502,
+    
+    #[msg("NOT_HASH_LOCKED: This lock has no hashlock")]
+    NotHashLocked = 1503,
+    
+    #[msg("NOT_INHERITANCE_LOCK: This lock has no heartbeat or heirs")]
+    NotInheritanceLock = 1504,
+    
+    #[msg("PROGRAM_PAUSED: New locks and deposits are paused, withdrawals remain available")]
+    ProgramPaused = 1505,
+    
+    #[msg("NOT_LEGACY_ACCOUNT: Account is not a time lock in the pre-upgrade layout")]
+    NotLegacyAccount = 1506,
+    
     // === SYSTEM ERRORS (1600-1699) ===
     #[msg("ARITHMETIC_OVERFLOW: Mathematical operation overflow")]
     ArithmeticOverflow = 1601,
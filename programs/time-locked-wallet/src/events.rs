@@ -1,13 +1,18 @@
 use anchor_lang::prelude::*;
 use crate::AssetType;
+use crate::state::{ReleaseSchedule, UnlockCondition};
 
 // === CREATION EVENTS ===
 #[event]
 pub struct TimeLockCreated {
     pub time_lock_account: Pubkey,
     pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub lock_id: Option<u64>,
     pub unlock_timestamp: i64,
+    pub unlock_condition: UnlockCondition,
     pub asset_type: AssetType,
+    pub schedule: ReleaseSchedule,
     pub current_timestamp: i64,  // For frontend calculations
 }
 
@@ -16,7 +21,9 @@ pub struct TimeLockCreated {
 pub struct DepositEvent {
     pub time_lock_account: Pubkey,
     pub depositor: Pubkey,
-    pub amount: u64,
+    pub amount: u64,             // Credited to the lock, after the fee
+    pub fee_amount: u64,         // Protocol fee in the deposited asset
+    pub flat_fee_lamports: u64,  // Flat protocol fee paid by the depositor
     pub new_balance: u64,
     pub timestamp: i64,
     pub asset_type: AssetType,  // For frontend filtering
@@ -28,12 +35,177 @@ pub struct WithdrawalEvent {
     pub time_lock_account: Pubkey,
     pub owner: Pubkey,
     pub recipient: Pubkey,
-    pub amount: u64,
+    pub amount: u64,             // Received by the recipient, after the fee
+    pub fee_amount: u64,         // Protocol fee in the withdrawn asset
+    pub flat_fee_lamports: u64,  // Flat protocol fee paid by the caller
     pub remaining_balance: u64,
     pub timestamp: i64,
     pub asset_type: AssetType,  // For frontend filtering
 }
 
+#[event]
+pub struct HashlockClaimed {
+    pub time_lock_account: Pubkey,
+    pub counterparty: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub preimage: Vec<u8>,  // Revealed secret, lets the other chain's leg be claimed
+    pub timestamp: i64,
+    pub asset_type: AssetType,
+}
+
+#[event]
+pub struct EarlyWithdrawalEvent {
+    pub time_lock_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,  // Gross amount taken out of the lock
+    pub penalty: u64,  // Part of `amount` paid to the penalty recipient
+    pub penalty_bps: u16,
+    pub penalty_recipient: Pubkey,
+    pub fee_amount: u64,  // Protocol fee taken out of the beneficiary's share
+    pub flat_fee_lamports: u64,  // Flat protocol fee paid by the beneficiary
+    pub remaining_balance: u64,
+    pub timestamp: i64,
+    pub asset_type: AssetType,
+}
+
+// === MANAGEMENT EVENTS ===
+#[event]
+pub struct LockExtended {
+    pub time_lock_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub previous_unlock_timestamp: i64,
+    pub new_unlock_timestamp: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferProposed {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub time_lock_account: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub withdrawal_rights_moved: bool,  // True on self-locks where the beneficiary follows the owner
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryRotated {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub previous_beneficiary: Pubkey,
+    pub new_beneficiary: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigConfigured {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub signers: Vec<Pubkey>,  // Empty when the multisig was removed
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LegacyAccountMigrated {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub payer: Pubkey,
+    pub rent_top_up: u64,  // Lamports added so the larger layout stays rent exempt
+    pub timestamp: i64,
+}
+
+// === RECOVERY EVENTS ===
+#[event]
+pub struct GuardiansConfigured {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub guardians: Vec<Pubkey>,  // Empty when guardians were removed
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryInitiated {
+    pub time_lock_account: Pubkey,
+    pub initiated_by: Pubkey,
+    pub current_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub executable_at: i64,  // Owner can cancel until then
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub cancelled_new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecoveryCompleted {
+    pub time_lock_account: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub timestamp: i64,
+}
+
+// === INHERITANCE EVENTS ===
+#[event]
+pub struct HeartbeatRecorded {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub next_deadline: i64,  // Heirs can claim after this timestamp
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GrantRevoked {
+    pub time_lock_account: Pubkey,
+    pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub vested_released: u64,   // Sent to the beneficiary
+    pub unvested_returned: u64, // Clawed back to the owner
+    pub timestamp: i64,
+    pub asset_type: AssetType,
+}
+
+// === CONFIG EVENTS ===
+#[event]
+pub struct ConfigUpdated {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub force_close_grace_seconds: i64,
+    pub force_close_max_bounty_lamports: u64,
+    pub min_lock_duration: i64,
+    pub max_lock_duration: i64,
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub flat_fee_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseStatusChanged {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
 // === SECURITY EVENTS ===
 #[event]
 pub struct ReentrancyDetected {
@@ -0,0 +1,357 @@
+use anchor_lang::prelude::*;
+use crate::state::{TimeLockAccount, Config, AssetType};
+use crate::errors::TimeLockError;
+use crate::events::{WithdrawalEvent, HashlockClaimed};
+use crate::instructions::withdraw::{WithdrawSol, WithdrawTokenPartial, transfer_lamports_from_lock, transfer_lamports_with_fee, transfer_tokens_with_fee};
+use crate::instructions::config::transfer_fee_lamports;
+use crate::{debug_msg, critical_msg, event_msg};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+// ============================================================================
+// CLAIM VESTED SOL
+// ============================================================================
+
+pub fn claim_vested_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Vested SOL claim initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    let claimable = time_lock_account.claimable_amount(current_timestamp)?;
+    require!(claimable > 0, TimeLockError::NothingToClaim);
+    let fee_amount = Config::bps_fee(claimable, ctx.accounts.config.withdrawal_fee_bps);
+    
+    debug_msg!("Claimable: {} lamports, already withdrawn: {} lamports",
+               claimable, time_lock_account.withdrawn_amount);
+    
+    // Update balances before transfer
+    time_lock_account.sol_balance = time_lock_account.sol_balance
+        .checked_sub(claimable)
+        .ok_or(TimeLockError::InsufficientBalance)?;
+    time_lock_account.amount = time_lock_account.amount
+        .checked_sub(claimable)
+        .ok_or(TimeLockError::InsufficientBalance)?;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(claimable)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    let remaining_balance = time_lock_account.sol_balance;
+    
+    let result = transfer_lamports_with_fee(
+        &time_lock_account.to_account_info(),
+        &ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        claimable,
+        fee_amount,
+    );
+    
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            event_msg!("Vested claim completed: {} lamports to {}, {} lamports remain locked",
+                      claimable - fee_amount, ctx.accounts.beneficiary.key(), remaining_balance);
+            
+            emit!(WithdrawalEvent {
+                time_lock_account: ctx.accounts.time_lock_account.key(),
+                owner: ctx.accounts.time_lock_account.owner,
+                recipient: ctx.accounts.beneficiary.key(),
+                amount: claimable - fee_amount,
+                fee_amount,
+                flat_fee_lamports,
+                remaining_balance,
+                timestamp: current_timestamp,
+                asset_type: AssetType::Sol,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Vested claim failed: {:?}", e);
+            Err(e)
+        }
+    }
+}
+
+// ============================================================================
+// CLAIM VESTED TOKENS
+// ============================================================================
+
+pub fn claim_vested_token(ctx: Context<WithdrawTokenPartial>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Vested token claim initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
+    require!(ctx.accounts.time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let claimable = time_lock_account.claimable_amount(current_timestamp)?;
+    require!(claimable > 0, TimeLockError::NothingToClaim);
+    require!(claimable <= time_lock_account.amount, TimeLockError::InsufficientBalance);
+    let fee_amount = Config::bps_fee(claimable, ctx.accounts.config.withdrawal_fee_bps);
+    
+    // Prepare PDA signing seeds
+    let owner_key = time_lock_account.owner;
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    let result = transfer_tokens_with_fee(
+        &ctx.accounts.token_from_vault,
+        &ctx.accounts.mint,
+        ctx.accounts.token_to_ata.to_account_info(),
+        ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        time_lock_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        signer,
+        claimable,
+        fee_amount,
+    );
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            time_lock_account.amount -= claimable;
+            time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+                .checked_add(claimable)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            
+            event_msg!("Vested claim completed: {} tokens to {}, {} tokens remain locked",
+                      claimable - fee_amount, ctx.accounts.beneficiary.key(), time_lock_account.amount);
+            
+            emit!(WithdrawalEvent {
+                time_lock_account: time_lock_account.key(),
+                owner: owner_key,
+                recipient: ctx.accounts.token_to_ata.key(),
+                amount: claimable - fee_amount,
+                fee_amount,
+                flat_fee_lamports,
+                remaining_balance: time_lock_account.amount,
+                timestamp: current_timestamp,
+                asset_type: AssetType::Token,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Vested token claim failed: {:?}", e);
+            Err(e)
+        }
+    }
+}
+
+// ============================================================================
+// CLAIM WITH PREIMAGE (HTLC)
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(preimage: Vec<u8>)]
+pub struct ClaimWithPreimageSol<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    /// CHECK: Must match the counterparty fixed at initialize
+    #[account(
+        mut,
+        constraint = time_lock_account.hashlock.as_ref().map(|hashlock| hashlock.counterparty) == Some(counterparty.key())
+            @ TimeLockError::Unauthorized
+    )]
+    pub counterparty: UncheckedAccount<'info>,
+
+    // Anyone holding the preimage may submit it, funds only ever go to the counterparty
+    pub caller: Signer<'info>,
+}
+
+pub fn claim_with_preimage_sol(ctx: Context<ClaimWithPreimageSol>, preimage: Vec<u8>) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Preimage claim initiated for account: {}", time_lock_account.key());
+    
+    time_lock_account.start_operation_with_monitoring(ctx.accounts.caller.key())?;
+    time_lock_account.validate_preimage_claim(&preimage, current_timestamp)?;
+    
+    let amount = time_lock_account.sol_balance;
+    require!(amount > 0, TimeLockError::InvalidAmount);
+    
+    let previous_sol_balance = time_lock_account.sol_balance;
+    let previous_amount = time_lock_account.amount;
+    let previous_withdrawn = time_lock_account.withdrawn_amount;
+    
+    // Update balances before transfer
+    time_lock_account.sol_balance = 0;
+    time_lock_account.amount = previous_amount.saturating_sub(amount);
+    time_lock_account.withdrawn_amount = previous_withdrawn
+        .checked_add(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    
+    let result = transfer_lamports_from_lock(
+        &time_lock_account.to_account_info(),
+        &ctx.accounts.counterparty.to_account_info(),
+        amount,
+    );
+    
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            event_msg!("Preimage claim completed: {} lamports to {}", amount, ctx.accounts.counterparty.key());
+            
+            emit!(HashlockClaimed {
+                time_lock_account: time_lock_account.key(),
+                counterparty: ctx.accounts.counterparty.key(),
+                recipient: ctx.accounts.counterparty.key(),
+                amount,
+                preimage,
+                timestamp: current_timestamp,
+                asset_type: AssetType::Sol,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Preimage claim failed, rolling back: {:?}", e);
+            time_lock_account.sol_balance = previous_sol_balance;
+            time_lock_account.amount = previous_amount;
+            time_lock_account.withdrawn_amount = previous_withdrawn;
+            Err(e)
+        }
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(preimage: Vec<u8>)]
+pub struct ClaimWithPreimageToken<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_from_vault.key() @ TimeLockError::InvalidTokenVault
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub token_from_vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Any token account of the locked mint held by the counterparty
+    #[account(
+        mut,
+        constraint = counterparty_token_account.mint == token_from_vault.mint @ TimeLockError::InvalidMint,
+        constraint = time_lock_account.hashlock.as_ref().map(|hashlock| hashlock.counterparty) == Some(counterparty_token_account.owner)
+            @ TimeLockError::Unauthorized
+    )]
+    pub counterparty_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Anyone holding the preimage may submit it, funds only ever go to the counterparty
+    pub caller: Signer<'info>,
+
+    #[account(address = token_from_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn claim_with_preimage_token(ctx: Context<ClaimWithPreimageToken>, preimage: Vec<u8>) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Preimage token claim initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation_with_monitoring(ctx.accounts.caller.key())?;
+    ctx.accounts.time_lock_account.validate_preimage_claim(&preimage, current_timestamp)?;
+    
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let amount = time_lock_account.amount;
+    require!(amount > 0, TimeLockError::InvalidAmount);
+    
+    // Prepare PDA signing seeds
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.token_from_vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.counterparty_token_account.to_account_info(),
+        authority: time_lock_account.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+    
+    let result = token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals);
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            time_lock_account.amount = 0;
+            time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+                .checked_add(amount)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            
+            event_msg!("Preimage token claim completed: {} tokens to {}",
+                      amount, ctx.accounts.counterparty_token_account.key());
+            
+            emit!(HashlockClaimed {
+                time_lock_account: time_lock_account.key(),
+                counterparty: ctx.accounts.counterparty_token_account.owner,
+                recipient: ctx.accounts.counterparty_token_account.key(),
+                amount,
+                preimage,
+                timestamp: current_timestamp,
+                asset_type: AssetType::Token,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Preimage token claim failed: {:?}", e);
+            Err(e)
+        }
+    }
+}
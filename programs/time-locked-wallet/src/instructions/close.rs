@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{TimeLockAccount, AssetType};
+use crate::state::{TimeLockAccount, Config, AssetType, UnlockCondition};
 use crate::errors::TimeLockError;
-use crate::events::AccountClosureEvent;
+use crate::events::{AccountClosureEvent, GrantRevoked, WithdrawalEvent};
+use crate::instructions::withdraw::transfer_lamports_from_lock;
 use crate::{debug_msg, critical_msg, event_msg};
-use anchor_spl::token::{Token, TokenAccount, CloseAccount};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked, CloseAccount};
+use anchor_spl::associated_token::AssociatedToken;
 
 // ============================================================================
 // CLOSE EMPTY ACCOUNT
@@ -13,22 +15,29 @@ use anchor_spl::token::{Token, TokenAccount, CloseAccount};
 pub struct CloseEmptyAccount<'info> {
     #[account(
         mut,
-        seeds = [b"time_lock", owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
         has_one = owner @ TimeLockError::Unauthorized,
         constraint = time_lock_account.sol_balance == 0 @ TimeLockError::AccountNotEmpty,
         constraint = time_lock_account.amount == 0 @ TimeLockError::AccountNotEmpty,
+        constraint = time_lock_account.basket_assets.iter().all(|asset| asset.amount == 0) @ TimeLockError::AccountNotEmpty,
         close = owner
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
     
+    // Basket vaults follow in remaining_accounts as (vault, mint, beneficiary token account) triples
+    // in basket order; multisig co-signers and a second token program for mixed baskets come after
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    // Only needed to close basket vaults
+    pub token_program: Option<Interface<'info, TokenInterface>>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn close_empty_account(ctx: Context<CloseEmptyAccount>) -> Result<()> {
+pub fn close_empty_account<'info>(ctx: Context<'_, '_, 'info, 'info, CloseEmptyAccount<'info>>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
     let time_lock_account = &ctx.accounts.time_lock_account;
     
     debug_msg!("Closing empty account: {}", ctx.accounts.time_lock_account.key());
@@ -39,10 +48,32 @@ pub fn close_empty_account(ctx: Context<CloseEmptyAccount>) -> Result<()> {
         TimeLockError::AccountNotEmpty
     );
     
+    // Basket vaults would otherwise be orphaned with no authority left, tokens sent to them
+    // directly go to the beneficiary like every other basket release
+    let (swept, vault_rent) = sweep_basket_vaults(
+        time_lock_account,
+        ctx.remaining_accounts,
+        ctx.accounts.token_program.as_ref().map(|program| program.to_account_info()),
+        ctx.accounts.owner.to_account_info(),
+    )?;
+    for (recipient, amount) in swept.iter().filter(|(_, amount)| *amount > 0) {
+        emit!(WithdrawalEvent {
+            time_lock_account: time_lock_account.key(),
+            owner: time_lock_account.owner,
+            recipient: *recipient,
+            amount: *amount,
+            fee_amount: 0,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: Clock::get()?.unix_timestamp,
+            asset_type: AssetType::Token,
+        });
+    }
+    
     // Calculate rent refund
     let rent_refund = Rent::get()?.minimum_balance(
         time_lock_account.to_account_info().data_len()
-    );
+    ) + vault_rent;
     
     event_msg!("Account closed, rent refunded: {} lamports to {}", 
                rent_refund, ctx.accounts.owner.key());
@@ -67,7 +98,7 @@ pub fn close_empty_account(ctx: Context<CloseEmptyAccount>) -> Result<()> {
 pub struct CloseTokenAccount<'info> {
     #[account(
         mut,
-        seeds = [b"time_lock", owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
         has_one = owner @ TimeLockError::Unauthorized,
         constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
@@ -84,13 +115,15 @@ pub struct CloseTokenAccount<'info> {
         constraint = token_vault.key() == time_lock_account.token_vault @ TimeLockError::InvalidTokenVault,
         constraint = token_vault.amount == 0 @ TimeLockError::AccountNotEmpty
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn close_token_account(ctx: Context<CloseTokenAccount>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
     let time_lock_account = &ctx.accounts.time_lock_account;
     
     debug_msg!("Closing token account: {}", ctx.accounts.time_lock_account.key());
@@ -109,12 +142,12 @@ pub fn close_token_account(ctx: Context<CloseTokenAccount>) -> Result<()> {
     let total_rent_refund = account_rent + vault_rent;
     
     // Prepare PDA signing seeds for closing token vault
-    let owner_key = ctx.accounts.owner.key();
-    let unlock_timestamp_le = time_lock_account.unlock_timestamp.to_le_bytes();
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
     let time_lock_seeds = &[
-        b"time_lock",
-        owner_key.as_ref(),
-        unlock_timestamp_le.as_ref(),
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
         &[time_lock_account.bump],
     ];
     let signer = &[&time_lock_seeds[..]];
@@ -132,7 +165,7 @@ pub fn close_token_account(ctx: Context<CloseTokenAccount>) -> Result<()> {
         signer,
     );
     
-    anchor_spl::token::close_account(close_vault_ctx)?;
+    token_interface::close_account(close_vault_ctx)?;
     
     event_msg!("Token account and vault closed, total rent refunded: {} lamports to {}", 
                total_rent_refund, ctx.accounts.owner.key());
@@ -149,27 +182,266 @@ pub fn close_token_account(ctx: Context<CloseTokenAccount>) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// REVOKE GRANT (creator clawback of unvested funds)
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RevokeSolGrant<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.revocable @ TimeLockError::NotRevocable,
+        // Owner and beneficiary can converge after creation through an ownership transfer or rotation
+        constraint = time_lock_account.beneficiary != time_lock_account.owner @ TimeLockError::SelfGrantNotRevocable,
+        constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType,
+        close = owner // Unvested lamports and rent return to the owner
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// CHECK: Receives the vested portion, validated by has_one against the stored beneficiary
+    #[account(mut)]
+    pub beneficiary: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_sol_grant(ctx: Context<RevokeSolGrant>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Revoking SOL grant: {}", time_lock_account.key());
+    
+    time_lock_account.start_operation()?;
+    
+    let vested_released = time_lock_account.claimable_amount(current_time)?;
+    let unvested_returned = time_lock_account.sol_balance.saturating_sub(vested_released);
+    
+    // Vested lamports go to the beneficiary, the rest leaves with the account closure
+    if vested_released > 0 {
+        transfer_lamports_from_lock(
+            &time_lock_account.to_account_info(),
+            &ctx.accounts.beneficiary.to_account_info(),
+            vested_released,
+        )?;
+    }
+    
+    time_lock_account.sol_balance = 0;
+    time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(vested_released)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.end_operation();
+    
+    critical_msg!("Grant revoked: {} lamports vested to beneficiary, {} lamports returned to owner", 
+                  vested_released, unvested_returned);
+    
+    emit!(GrantRevoked {
+        time_lock_account: time_lock_account.key(),
+        owner: ctx.accounts.owner.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        vested_released,
+        unvested_returned,
+        timestamp: current_time,
+        asset_type: AssetType::Sol,
+    });
+    
+    // Account will be automatically closed by Anchor
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeTokenGrant<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.revocable @ TimeLockError::NotRevocable,
+        constraint = time_lock_account.beneficiary != time_lock_account.owner @ TimeLockError::SelfGrantNotRevocable,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_vault.key() @ TimeLockError::InvalidTokenVault,
+        close = owner
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// CHECK: Validated by has_one against the stored beneficiary
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_vault.mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_vault.mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(address = token_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_token_grant(ctx: Context<RevokeTokenGrant>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Revoking token grant: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation()?;
+    
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let vested_released = time_lock_account.claimable_amount(current_time)?;
+    // Anything sitting in the vault beyond the vested portion goes back to the owner
+    let unvested_returned = ctx.accounts.token_vault.amount.saturating_sub(vested_released);
+    
+    // Prepare PDA signing seeds
+    let owner_key = ctx.accounts.owner.key();
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    if vested_released > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.token_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.beneficiary_token_account.to_account_info(),
+            authority: time_lock_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token_interface::transfer_checked(cpi_ctx, vested_released, ctx.accounts.mint.decimals)?;
+    }
+    
+    if unvested_returned > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.token_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: time_lock_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer,
+        );
+        token_interface::transfer_checked(cpi_ctx, unvested_returned, ctx.accounts.mint.decimals)?;
+    }
+    
+    // Close the now empty vault, refunding its rent to the owner
+    let close_vault_accounts = CloseAccount {
+        account: ctx.accounts.token_vault.to_account_info(),
+        destination: ctx.accounts.owner.to_account_info(),
+        authority: time_lock_account.to_account_info(),
+    };
+    let close_vault_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_vault_accounts,
+        signer,
+    );
+    token_interface::close_account(close_vault_ctx)?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(vested_released)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.end_operation();
+    
+    critical_msg!("Grant revoked: {} tokens vested to beneficiary, {} tokens returned to owner", 
+                  vested_released, unvested_returned);
+    
+    emit!(GrantRevoked {
+        time_lock_account: time_lock_account.key(),
+        owner: owner_key,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        vested_released,
+        unvested_returned,
+        timestamp: current_time,
+        asset_type: AssetType::Token,
+    });
+    
+    // Time lock account will be automatically closed by Anchor
+    Ok(())
+}
+
 // ============================================================================
 // FORCE CLOSE EXPIRED ACCOUNT (Admin/Cleanup)
 // ============================================================================
+// Routing rule for all three force-close paths: the principal goes to the beneficiary and the rent to the
+// current owner, not everything to `original_owner`. An expired lock's funds are the beneficiary's to withdraw,
+// and after an ownership transfer or on a grant the creator has no claim left; on a plain self-lock the three
+// keys are the same, so the funds still return to whoever locked them.
 
 #[derive(Accounts)]
 pub struct ForceCloseExpired<'info> {
     #[account(
         mut,
-        seeds = [b"time_lock", original_owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
-        constraint = Clock::get()?.unix_timestamp > time_lock_account.unlock_timestamp + 86400 * 365 @ TimeLockError::TimeLockNotExpired, // 1 year grace period
-        close = rent_collector
+        // The grace period is measured in wall-clock time, slot and epoch locks have no deadline to measure from
+        constraint = time_lock_account.unlock_condition == UnlockCondition::Timestamp @ TimeLockError::InvalidUnlockCondition,
+        // Inheritance locks open at creation, their funds belong to the owner or the heirs
+        constraint = time_lock_account.inheritance.is_none() @ TimeLockError::InvalidUnlockCondition,
+        // Locks holding a vault go through force_close_expired_token or _basket so the tokens are not orphaned
+        constraint = time_lock_account.token_vault == Pubkey::default() @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.basket_assets.is_empty() @ TimeLockError::InvalidAssetType,
+        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        close = owner // Remaining rent goes back to the current owner
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
     
-    /// CHECK: Original owner address for PDA derivation
-    pub original_owner: AccountInfo<'info>,
+    /// CHECK: Current owner, receives the remaining rent
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    
+    /// CHECK: Holder of the withdrawal rights, receives the principal
+    #[account(mut)]
+    pub beneficiary: AccountInfo<'info>,
     
+    // Cranker, paid a bounded bounty out of the lock's rent
     #[account(mut)]
     pub rent_collector: Signer<'info>,
 
+    // Program-wide policy (grace period and bounty cap)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -177,30 +449,383 @@ pub fn force_close_expired(ctx: Context<ForceCloseExpired>) -> Result<()> {
     let time_lock_account = &ctx.accounts.time_lock_account;
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Ensure account has been expired for more than 1 year (cleanup threshold)
-    let cleanup_threshold = time_lock_account.unlock_timestamp + (86400 * 365); // 1 year
+    // Ensure account has been expired for longer than the configured grace period
+    let cleanup_threshold = ctx.accounts.config.force_close_deadline(time_lock_account.unlock_timestamp)?;
     require!(
         current_time > cleanup_threshold,
         TimeLockError::TimeLockNotExpired
     );
     
-    let rent_refund = Rent::get()?.minimum_balance(
-        time_lock_account.to_account_info().data_len()
+    let time_lock_info = time_lock_account.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(time_lock_info.data_len());
+    let principal = time_lock_info.lamports().saturating_sub(rent_reserve);
+    transfer_lamports_from_lock(&time_lock_info, &ctx.accounts.beneficiary.to_account_info(), principal)?;
+    let bounty = pay_force_close_bounty(&time_lock_info, &ctx.accounts.rent_collector.to_account_info(), rent_reserve, ctx.accounts.config.force_close_max_bounty_lamports)?;
+    
+    critical_msg!("Force closing expired account: {}, expired {} days ago, {} lamports returned to {}", 
+                  ctx.accounts.time_lock_account.key(),
+                  (current_time - time_lock_account.unlock_timestamp) / 86400,
+                  principal,
+                  ctx.accounts.beneficiary.key());
+    
+    if principal > 0 {
+        emit!(WithdrawalEvent {
+            time_lock_account: ctx.accounts.time_lock_account.key(),
+            owner: time_lock_account.owner,
+            recipient: ctx.accounts.beneficiary.key(),
+            amount: principal,
+            fee_amount: 0,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: current_time,
+            asset_type: time_lock_account.asset_type.clone(),
+        });
+    }
+    
+    emit!(AccountClosureEvent {
+        time_lock_account: ctx.accounts.time_lock_account.key(),
+        owner: time_lock_account.owner,
+        rent_refunded: rent_reserve - bounty,
+        timestamp: current_time,
+        closure_reason: format!("Force closure after {} days expired, {} lamports bounty", 
+                               (current_time - time_lock_account.unlock_timestamp) / 86400, bounty),
+    });
+    
+    // Anchor closes the account and sends the remaining rent to the owner
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ForceCloseExpiredToken<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.unlock_condition == UnlockCondition::Timestamp @ TimeLockError::InvalidUnlockCondition,
+        constraint = time_lock_account.inheritance.is_none() @ TimeLockError::InvalidUnlockCondition,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_vault.key() @ TimeLockError::InvalidTokenVault,
+        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        close = owner // Remaining rent goes back to the current owner
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+    
+    /// CHECK: Current owner, receives the lock and vault rent
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    
+    /// CHECK: Holder of the withdrawal rights, receives the tokens
+    pub beneficiary: AccountInfo<'info>,
+    
+    // Cranker, paid a bounded bounty out of the lock's rent; funds the beneficiary's ATA if missing
+    #[account(mut)]
+    pub rent_collector: Signer<'info>,
+
+    // Program-wide policy (grace period and bounty cap)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = rent_collector,
+        associated_token::mint = mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program,
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn force_close_expired_token(ctx: Context<ForceCloseExpiredToken>) -> Result<()> {
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Ensure account has been expired for longer than the configured grace period
+    let cleanup_threshold = ctx.accounts.config.force_close_deadline(time_lock_account.unlock_timestamp)?;
+    require!(
+        current_time > cleanup_threshold,
+        TimeLockError::TimeLockNotExpired
     );
     
-    critical_msg!("Force closing expired account: {}, expired {} days ago", 
+    // Prepare PDA signing seeds
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    // Return whatever the vault holds, including tokens sent to it directly
+    let principal = ctx.accounts.token_vault.amount;
+    if principal > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.token_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.beneficiary_token_account.to_account_info(),
+            authority: time_lock_account.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        token_interface::transfer_checked(cpi_ctx, principal, ctx.accounts.mint.decimals)?;
+    }
+    
+    let vault_rent = ctx.accounts.token_vault.to_account_info().lamports();
+    let close_vault_accounts = CloseAccount {
+        account: ctx.accounts.token_vault.to_account_info(),
+        destination: ctx.accounts.owner.to_account_info(),
+        authority: time_lock_account.to_account_info(),
+    };
+    let close_vault_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_vault_accounts,
+        signer,
+    );
+    token_interface::close_account(close_vault_ctx)?;
+    
+    let time_lock_info = time_lock_account.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(time_lock_info.data_len());
+    let bounty = pay_force_close_bounty(&time_lock_info, &ctx.accounts.rent_collector.to_account_info(), rent_reserve, ctx.accounts.config.force_close_max_bounty_lamports)?;
+    
+    critical_msg!("Force closing expired token account: {}, {} tokens returned to {}", 
                   ctx.accounts.time_lock_account.key(),
-                  (current_time - time_lock_account.unlock_timestamp) / 86400);
+                  principal,
+                  ctx.accounts.beneficiary_token_account.key());
+    
+    emit!(WithdrawalEvent {
+        time_lock_account: ctx.accounts.time_lock_account.key(),
+        owner: time_lock_account.owner,
+        recipient: ctx.accounts.beneficiary_token_account.key(),
+        amount: principal,
+        fee_amount: 0,
+        flat_fee_lamports: 0,
+        remaining_balance: 0,
+        timestamp: current_time,
+        asset_type: AssetType::Token,
+    });
     
     emit!(AccountClosureEvent {
         time_lock_account: ctx.accounts.time_lock_account.key(),
         owner: time_lock_account.owner,
-        rent_refunded: rent_refund,
+        rent_refunded: rent_reserve - bounty + vault_rent,
+        timestamp: current_time,
+        closure_reason: format!("Force closure after {} days expired, {} lamports bounty", 
+                               (current_time - time_lock_account.unlock_timestamp) / 86400, bounty),
+    });
+    
+    // Anchor closes the account and sends the remaining rent to the owner
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ForceCloseExpiredBasket<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.unlock_condition == UnlockCondition::Timestamp @ TimeLockError::InvalidUnlockCondition,
+        constraint = time_lock_account.inheritance.is_none() @ TimeLockError::InvalidUnlockCondition,
+        constraint = time_lock_account.asset_type == AssetType::Basket @ TimeLockError::InvalidAssetType,
+        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        close = owner // Remaining rent goes back to the current owner
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+    
+    /// CHECK: Current owner, receives the lock and vault rent
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+    
+    // Every basket vault follows in remaining_accounts as a (vault, mint, beneficiary token account)
+    // triple in basket order, plus the second token program for mixed baskets
+    /// CHECK: Holder of the withdrawal rights, receives the SOL and tokens
+    #[account(mut)]
+    pub beneficiary: AccountInfo<'info>,
+    
+    // Cranker, paid a bounded bounty out of the lock's rent
+    #[account(mut)]
+    pub rent_collector: Signer<'info>,
+
+    // Program-wide policy (grace period and bounty cap)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn force_close_expired_basket<'info>(ctx: Context<'_, '_, 'info, 'info, ForceCloseExpiredBasket<'info>>) -> Result<()> {
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Ensure account has been expired for longer than the configured grace period
+    let cleanup_threshold = ctx.accounts.config.force_close_deadline(time_lock_account.unlock_timestamp)?;
+    require!(
+        current_time > cleanup_threshold,
+        TimeLockError::TimeLockNotExpired
+    );
+    
+    // Token legs, each vault drained to the beneficiary and closed to the owner
+    let (swept, vault_rent) = sweep_basket_vaults(
+        time_lock_account,
+        ctx.remaining_accounts,
+        Some(ctx.accounts.token_program.to_account_info()),
+        ctx.accounts.owner.to_account_info(),
+    )?;
+    for (recipient, amount) in swept.iter().filter(|(_, amount)| *amount > 0) {
+        emit!(WithdrawalEvent {
+            time_lock_account: time_lock_account.key(),
+            owner: time_lock_account.owner,
+            recipient: *recipient,
+            amount: *amount,
+            fee_amount: 0,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: current_time,
+            asset_type: AssetType::Token,
+        });
+    }
+    
+    // Native SOL leg, everything above the rent reserve
+    let time_lock_info = time_lock_account.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(time_lock_info.data_len());
+    let principal = time_lock_info.lamports().saturating_sub(rent_reserve);
+    transfer_lamports_from_lock(&time_lock_info, &ctx.accounts.beneficiary.to_account_info(), principal)?;
+    let bounty = pay_force_close_bounty(&time_lock_info, &ctx.accounts.rent_collector.to_account_info(), rent_reserve, ctx.accounts.config.force_close_max_bounty_lamports)?;
+    
+    critical_msg!("Force closing expired basket: {}, {} lamports and {} mints returned to {}", 
+                  ctx.accounts.time_lock_account.key(),
+                  principal,
+                  swept.len(),
+                  ctx.accounts.beneficiary.key());
+    
+    if principal > 0 {
+        emit!(WithdrawalEvent {
+            time_lock_account: time_lock_account.key(),
+            owner: time_lock_account.owner,
+            recipient: ctx.accounts.beneficiary.key(),
+            amount: principal,
+            fee_amount: 0,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: current_time,
+            asset_type: AssetType::Sol,
+        });
+    }
+    
+    emit!(AccountClosureEvent {
+        time_lock_account: ctx.accounts.time_lock_account.key(),
+        owner: time_lock_account.owner,
+        rent_refunded: rent_reserve - bounty + vault_rent,
         timestamp: current_time,
-        closure_reason: format!("Force closure after {} days expired", 
-                               (current_time - time_lock_account.unlock_timestamp) / 86400),
+        closure_reason: format!("Force closure after {} days expired, {} lamports bounty", 
+                               (current_time - time_lock_account.unlock_timestamp) / 86400, bounty),
     });
     
-    // Rent goes to cleanup caller as incentive
+    // Anchor closes the account and sends the remaining rent to the owner
     Ok(())
 }
+
+// Drains every basket vault into the beneficiary's token account for its mint and closes it,
+// returning what each leg moved and the total vault rent sent to rent_destination
+fn sweep_basket_vaults<'info>(
+    time_lock_account: &Account<'info, TimeLockAccount>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    token_program: Option<AccountInfo<'info>>,
+    rent_destination: AccountInfo<'info>,
+) -> Result<(Vec<(Pubkey, u64)>, u64)> {
+    let basket_assets = &time_lock_account.basket_assets;
+    if basket_assets.is_empty() {
+        return Ok((Vec::new(), 0));
+    }
+    require!(
+        remaining_accounts.len() >= basket_assets.len() * 3,
+        TimeLockError::InvalidTokenVault
+    );
+    
+    // Prepare PDA signing seeds
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    let mut swept = Vec::with_capacity(basket_assets.len());
+    let mut vault_rent: u64 = 0;
+    for (asset, accounts) in basket_assets.iter().zip(remaining_accounts.chunks(3)) {
+        let (vault_info, mint_info, destination_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        require!(vault_info.key() == asset.vault, TimeLockError::InvalidTokenVault);
+        require!(mint_info.key() == asset.mint, TimeLockError::InvalidMint);
+        
+        let vault = InterfaceAccount::<TokenAccount>::try_from(vault_info)?;
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        let destination = InterfaceAccount::<TokenAccount>::try_from(destination_info)?;
+        require!(destination.mint == asset.mint, TimeLockError::InvalidMint);
+        require!(destination.owner == time_lock_account.beneficiary, TimeLockError::Unauthorized);
+        
+        // Each leg is driven by the token program that owns its vault
+        let leg_token_program = token_program
+            .iter()
+            .chain(remaining_accounts.iter())
+            .find(|account| account.key == vault_info.owner)
+            .cloned()
+            .ok_or(TimeLockError::InvalidTokenVault)?;
+        
+        if vault.amount > 0 {
+            let cpi_accounts = TransferChecked {
+                from: vault_info.clone(),
+                mint: mint_info.clone(),
+                to: destination_info.clone(),
+                authority: time_lock_account.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(leg_token_program.clone(), cpi_accounts, signer);
+            token_interface::transfer_checked(cpi_ctx, vault.amount, mint.decimals)?;
+        }
+        
+        vault_rent = vault_rent
+            .checked_add(vault_info.lamports())
+            .ok_or(TimeLockError::ArithmeticOverflow)?;
+        let close_vault_accounts = CloseAccount {
+            account: vault_info.clone(),
+            destination: rent_destination.clone(),
+            authority: time_lock_account.to_account_info(),
+        };
+        let close_vault_ctx = CpiContext::new_with_signer(leg_token_program, close_vault_accounts, signer);
+        token_interface::close_account(close_vault_ctx)?;
+        
+        swept.push((destination_info.key(), vault.amount));
+    }
+    
+    Ok((swept, vault_rent))
+}
+
+// Pays the cranker out of the lock's rent reserve, capped so principal is never touched
+fn pay_force_close_bounty<'info>(
+    time_lock_info: &AccountInfo<'info>,
+    rent_collector: &AccountInfo<'info>,
+    rent_reserve: u64,
+    max_bounty: u64,
+) -> Result<u64> {
+    let bounty = rent_reserve.min(max_bounty);
+    
+    **time_lock_info.try_borrow_mut_lamports()? -= bounty;
+    **rent_collector.try_borrow_mut_lamports()? += bounty;
+    
+    Ok(bounty)
+}
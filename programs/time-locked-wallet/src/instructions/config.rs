@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::program::TimeLockedWallet;
+use crate::state::{Config, ConfigParams};
+use crate::errors::TimeLockError;
+use crate::events::{ConfigUpdated, PauseStatusChanged};
+use crate::event_msg;
+use anchor_lang::system_program;
+
+// ============================================================================
+// INITIALIZE CONFIG
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Config::INIT_SPACE,
+        seeds = [Config::SEED],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    // Only the upgrade authority may create the singleton, so nobody can front-run the first admin
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ TimeLockError::Unauthorized)]
+    pub program: Program<'info, TimeLockedWallet>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ TimeLockError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+    params.validate()?;
+    
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.bump = ctx.bumps.config;
+    config.paused = false;
+    config.apply(&params);
+    
+    msg!("⚙️ Program config initialized, admin: {}", config.admin);
+    
+    emit_config_updated(config)
+}
+
+// ============================================================================
+// UPDATE CONFIG
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump = config.bump,
+        has_one = admin @ TimeLockError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams, new_admin: Option<Pubkey>) -> Result<()> {
+    params.validate()?;
+    
+    let config = &mut ctx.accounts.config;
+    config.apply(&params);
+    
+    if let Some(new_admin) = new_admin {
+        event_msg!("Config admin handed from {} to {}", config.admin, new_admin);
+        config.admin = new_admin;
+    }
+    
+    emit_config_updated(config)
+}
+
+// ============================================================================
+// EMERGENCY PAUSE
+// ============================================================================
+
+// Shares UpdateConfig's accounts, only the admin may flip the switch
+pub fn set_paused(ctx: Context<UpdateConfig>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+    
+    if paused {
+        msg!("⏸️ New locks and deposits paused by {}", ctx.accounts.admin.key());
+    } else {
+        msg!("▶️ New locks and deposits resumed by {}", ctx.accounts.admin.key());
+    }
+    
+    emit!(PauseStatusChanged {
+        config: config.key(),
+        admin: ctx.accounts.admin.key(),
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+fn emit_config_updated(config: &Account<Config>) -> Result<()> {
+    emit!(ConfigUpdated {
+        config: config.key(),
+        admin: config.admin,
+        treasury: config.treasury,
+        force_close_grace_seconds: config.force_close_grace_seconds,
+        force_close_max_bounty_lamports: config.force_close_max_bounty_lamports,
+        min_lock_duration: config.min_lock_duration,
+        max_lock_duration: config.max_lock_duration,
+        deposit_fee_bps: config.deposit_fee_bps,
+        withdrawal_fee_bps: config.withdrawal_fee_bps,
+        flat_fee_lamports: config.flat_fee_lamports,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// FEE COLLECTION
+// ============================================================================
+
+// Treasury account supplied by the client, only required once a fee is actually due
+pub(crate) fn require_treasury<'info>(treasury: Option<AccountInfo<'info>>) -> Result<AccountInfo<'info>> {
+    treasury.ok_or_else(|| error!(TimeLockError::InvalidTreasury))
+}
+
+// Pays `lamports` of protocol fee from a signer's wallet to the treasury
+pub(crate) fn transfer_fee_lamports<'info>(
+    payer: AccountInfo<'info>,
+    treasury: Option<AccountInfo<'info>>,
+    system_program: AccountInfo<'info>,
+    lamports: u64,
+) -> Result<()> {
+    if lamports == 0 {
+        return Ok(());
+    }
+    
+    let cpi_accounts = system_program::Transfer {
+        from: payer,
+        to: require_treasury(treasury)?,
+    };
+    system_program::transfer(CpiContext::new(system_program, cpi_accounts), lamports)
+}
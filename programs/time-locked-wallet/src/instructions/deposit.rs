@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::{TimeLockAccount, AssetType};
+use crate::state::{TimeLockAccount, Config, AssetType, BasketAsset};
 use crate::errors::TimeLockError;
 use crate::events::DepositEvent;
+use crate::instructions::config::{require_treasury, transfer_fee_lamports};
 use anchor_lang::system_program;
-use anchor_spl::token::{Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 use anchor_spl::associated_token::AssociatedToken;
 
 // ============================================================================
@@ -15,15 +16,27 @@ use anchor_spl::associated_token::AssociatedToken;
 pub struct DepositSol<'info> {
     #[account(
         mut,
-        seeds = [b"time_lock", initializer.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
-        constraint = time_lock_account.owner == initializer.key() @ TimeLockError::InvalidAssetType,
-        constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType
+        constraint = matches!(time_lock_account.asset_type, AssetType::Sol | AssetType::Basket) @ TimeLockError::InvalidAssetType
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
 
+    // Anyone may top up an existing lock, not only its owner
     #[account(mut)]
-    pub initializer: Signer<'info>,
+    pub depositor: Signer<'info>,
+
+    // Program-wide policy (fees, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Fee destination, only required while the config charges fees
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -36,6 +49,18 @@ pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
     require!(ctx.accounts.time_lock_account.is_initialized, TimeLockError::NotInitialized);
     require!(amount > 0, TimeLockError::InvalidAmount);
     
+    // 💸 Protocol fees: the bps part comes out of the deposit, the flat part on top, both in lamports
+    let (fee_amount, total_fee_lamports) = ctx.accounts.config.sol_deposit_fees(amount)?;
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        total_fee_lamports,
+    )?;
+    // The lock is credited with what remains after the bps fee
+    let amount = amount - fee_amount;
+    
     // Reentrancy protection
     ctx.accounts.time_lock_account.start_operation()?;
     
@@ -49,7 +74,7 @@ pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
     
     // Execute SOL transfer using system_program CPI
     let cpi_accounts = system_program::Transfer {
-        from: ctx.accounts.initializer.to_account_info(),
+        from: ctx.accounts.depositor.to_account_info(),
         to: ctx.accounts.time_lock_account.to_account_info(),
     };
     let cpi_program = ctx.accounts.system_program.to_account_info();
@@ -78,11 +103,13 @@ pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
             // Emit deposit event
             emit!(DepositEvent {
                 time_lock_account: time_lock_key,
-                depositor: ctx.accounts.initializer.key(),
+                depositor: ctx.accounts.depositor.key(),
                 amount,
+                fee_amount,
+                flat_fee_lamports,
                 new_balance: time_lock_account.sol_balance,
                 timestamp: Clock::get()?.unix_timestamp,
-                asset_type: AssetType::Sol,
+                asset_type: time_lock_account.asset_type.clone(),
             });
             
             // ✅ End operation
@@ -94,7 +121,7 @@ pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
             
             // ✅ End operation on failure
             time_lock_account.end_operation();
-            Err(e.into())
+            Err(e)
         }
     }
 }
@@ -108,35 +135,61 @@ pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
 pub struct DepositToken<'info> {
     #[account(
         mut,
-        seeds = [b"time_lock", initializer.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
-        constraint = time_lock_account.owner == initializer.key() @ TimeLockError::InvalidAssetType,
-        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        // The mint is fixed at creation, the vault by the first deposit
+        constraint = time_lock_account.mint == mint.key() @ TimeLockError::InvalidMint,
+        constraint = time_lock_account.token_vault == Pubkey::default() || time_lock_account.token_vault == token_vault.key() @ TimeLockError::InvalidTokenVault
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
     
+    // Anyone may top up an existing lock, not only its owner
     #[account(mut)]
-    pub initializer: Signer<'info>,
+    pub depositor: Signer<'info>,
 
-    /// CHECK: Token mint account
-    pub mint: AccountInfo<'info>,
+    // Legacy SPL Token or Token-2022 mint
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
         associated_token::mint = mint,
-        associated_token::authority = initializer,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
     )]
-    pub token_from_ata: Account<'info, TokenAccount>,
+    pub token_from_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init_if_needed,
-        payer = initializer,
+        payer = depositor,
         associated_token::mint = mint,
         associated_token::authority = time_lock_account,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    // Program-wide policy (fees, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -148,46 +201,78 @@ pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
     // Check initialization
     require!(ctx.accounts.time_lock_account.is_initialized, TimeLockError::NotInitialized);
     
+    // 💸 Protocol fees: the bps part in tokens out of the deposit, the flat part in lamports on top
+    let fee_amount = Config::bps_fee(amount, ctx.accounts.config.deposit_fee_bps);
+    if fee_amount > 0 {
+        let treasury_token_account = require_treasury(
+            ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        )?;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.token_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: treasury_token_account,
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, fee_amount, ctx.accounts.mint.decimals)?;
+    }
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    // The vault receives what remains after the bps fee
+    let amount = amount - fee_amount;
+    
     // 🔒 Reentrancy protection
     ctx.accounts.time_lock_account.start_operation()?;
 
     // Get keys before mutable borrow
     let time_lock_key = ctx.accounts.time_lock_account.key();
-    let depositor_key = ctx.accounts.initializer.key();
+    let depositor_key = ctx.accounts.depositor.key();
+
+    // Transfer-fee mints deliver less than `amount`, so measure what the vault receives
+    let vault_balance_before = ctx.accounts.token_vault.amount;
 
     // Setup CPI for token transfer
-    let cpi_accounts = Transfer {
+    let cpi_accounts = TransferChecked {
         from: ctx.accounts.token_from_ata.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
         to: ctx.accounts.token_vault.to_account_info(),
-        authority: ctx.accounts.initializer.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
     };
 
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
     // Execute token transfer
-    let result = anchor_spl::token::transfer(cpi_ctx, amount);
+    let result = token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)
+        .and_then(|_| received_by_vault(&mut ctx.accounts.token_vault, vault_balance_before));
     
     let time_lock_account = &mut ctx.accounts.time_lock_account;
     
     match result {
-        Ok(_) => {
-            // Set token_vault if not already set
+        Ok(received) => {
+            // The first deposit records the vault of the lock's mint
             if time_lock_account.token_vault == Pubkey::default() {
                 time_lock_account.token_vault = ctx.accounts.token_vault.key();
             }
             
             // Update amount with overflow check
-            time_lock_account.amount = time_lock_account.amount.checked_add(amount)
+            time_lock_account.amount = time_lock_account.amount.checked_add(received)
                 .ok_or(TimeLockError::ArithmeticOverflow)?;
 
-            msg!("Deposited {} tokens to time-locked wallet", amount);
+            msg!("Deposited {} tokens to time-locked wallet ({} sent)", received, amount);
             
             // Emit deposit event
             emit!(DepositEvent {
                 time_lock_account: time_lock_key,
                 depositor: depositor_key,
-                amount,
+                amount: received,
+                fee_amount,
+                flat_fee_lamports,
                 new_balance: time_lock_account.amount,
                 timestamp: Clock::get()?.unix_timestamp,
                 asset_type: AssetType::Token,
@@ -200,7 +285,188 @@ pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
         Err(e) => {
             // ✅ End operation on error
             time_lock_account.end_operation();
-            Err(e.into())
+            Err(e)
         }
     }
 }
+
+// ============================================================================
+// BASKET TOKEN DEPOSIT
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct DepositBasketToken<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.asset_type == AssetType::Basket @ TimeLockError::InvalidAssetType
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+    
+    // Anyone may top up an existing lock, not only its owner
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    // Legacy SPL Token or Token-2022 mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub token_from_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // One vault per mint, all owned by the same lock PDA
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = time_lock_account,
+        associated_token::token_program = token_program,
+    )]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    // Program-wide policy (fees, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deposit_basket_token(ctx: Context<DepositBasketToken>, amount: u64) -> Result<()> {
+    require!(amount > 0, TimeLockError::InvalidAmount);
+    require!(ctx.accounts.time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    let mint = ctx.accounts.mint.key();
+    let is_new_mint = !ctx.accounts.time_lock_account.basket_assets.iter().any(|asset| asset.mint == mint);
+    // Anyone may top up a listed mint, only the owner may add a new one to the basket
+    require!(
+        !is_new_mint || ctx.accounts.depositor.key() == ctx.accounts.time_lock_account.owner,
+        TimeLockError::Unauthorized
+    );
+    require!(
+        !is_new_mint || ctx.accounts.time_lock_account.basket_assets.len() < BasketAsset::MAX_ASSETS,
+        TimeLockError::BasketFull
+    );
+    
+    // 💸 Protocol fees: the bps part in tokens out of the deposit, the flat part in lamports on top
+    let fee_amount = Config::bps_fee(amount, ctx.accounts.config.deposit_fee_bps);
+    if fee_amount > 0 {
+        let treasury_token_account = require_treasury(
+            ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        )?;
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.token_from_ata.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: treasury_token_account,
+            authority: ctx.accounts.depositor.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token_interface::transfer_checked(cpi_ctx, fee_amount, ctx.accounts.mint.decimals)?;
+    }
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    // The vault receives what remains after the bps fee
+    let amount = amount - fee_amount;
+    
+    // 🔒 Reentrancy protection
+    ctx.accounts.time_lock_account.start_operation()?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let depositor_key = ctx.accounts.depositor.key();
+    
+    let vault_balance_before = ctx.accounts.token_vault.amount;
+    
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.token_from_ata.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.token_vault.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    
+    let result = token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)
+        .and_then(|_| received_by_vault(&mut ctx.accounts.token_vault, vault_balance_before));
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    
+    match result {
+        Ok(received) => {
+            if is_new_mint {
+                time_lock_account.basket_assets.push(BasketAsset {
+                    mint,
+                    vault: ctx.accounts.token_vault.key(),
+                    amount: 0,
+                });
+            }
+            
+            let asset = time_lock_account.basket_assets
+                .iter_mut()
+                .find(|asset| asset.mint == mint)
+                .ok_or(TimeLockError::InvalidMint)?;
+            asset.amount = asset.amount
+                .checked_add(received)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            let new_balance = asset.amount;
+            
+            msg!("Deposited {} tokens of mint {} to basket ({} sent)", received, mint, amount);
+            
+            emit!(DepositEvent {
+                time_lock_account: time_lock_key,
+                depositor: depositor_key,
+                amount: received,
+                fee_amount,
+                flat_fee_lamports,
+                new_balance,
+                timestamp: Clock::get()?.unix_timestamp,
+                asset_type: AssetType::Basket,
+            });
+            
+            time_lock_account.end_operation();
+            Ok(())
+        },
+        Err(e) => {
+            time_lock_account.end_operation();
+            Err(e)
+        }
+    }
+}
+
+// Tokens that actually reached the vault, net of any Token-2022 transfer fee
+fn received_by_vault(token_vault: &mut InterfaceAccount<'_, TokenAccount>, balance_before: u64) -> Result<u64> {
+    token_vault.reload()?;
+    let received = token_vault.amount
+        .checked_sub(balance_before)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    require!(received > 0, TimeLockError::InvalidAmount);
+    Ok(received)
+}
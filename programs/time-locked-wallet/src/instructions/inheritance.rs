@@ -0,0 +1,280 @@
+use anchor_lang::prelude::*;
+use crate::state::{TimeLockAccount, AssetType};
+use crate::errors::TimeLockError;
+use crate::events::{HeartbeatRecorded, WithdrawalEvent, AccountClosureEvent};
+use crate::instructions::withdraw::transfer_lamports_from_lock;
+use crate::{debug_msg, critical_msg, event_msg};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked, CloseAccount};
+
+// ============================================================================
+// HEARTBEAT
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    let inheritance = ctx.accounts.time_lock_account.inheritance
+        .as_mut()
+        .ok_or(TimeLockError::NotInheritanceLock)?;
+    
+    // A late heartbeat still counts as long as no heir has claimed yet
+    inheritance.last_heartbeat = current_timestamp;
+    let next_deadline = inheritance.deadline();
+    
+    event_msg!("Heartbeat recorded, heirs can claim after {}", next_deadline);
+    
+    emit!(HeartbeatRecorded {
+        time_lock_account: time_lock_key,
+        owner: ctx.accounts.owner.key(),
+        next_deadline,
+        timestamp: current_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM INHERITANCE (SOL)
+// ============================================================================
+// Heirs split only the locked funds; the lock's and vault's rent go back to the original owner who paid them,
+// so no heir gains anything by being the one who cranks the claim
+
+#[derive(Accounts)]
+pub struct ClaimInheritanceSol<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType,
+        has_one = original_owner @ TimeLockError::Unauthorized,
+        close = original_owner // Rent goes back to whoever paid it
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Any listed heir, every heir's wallet follows in remaining_accounts in listed order
+    pub heir: Signer<'info>,
+
+    /// CHECK: Creator of the lock, receives the rent
+    #[account(mut)]
+    pub original_owner: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_inheritance_sol<'info>(ctx: Context<'_, '_, '_, 'info, ClaimInheritanceSol<'info>>) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Inheritance claim initiated for account: {}", time_lock_account.key());
+    
+    time_lock_account.start_operation_with_monitoring(ctx.accounts.heir.key())?;
+    
+    let inheritance = time_lock_account.inheritance.clone().ok_or(TimeLockError::NotInheritanceLock)?;
+    require!(inheritance.has_lapsed(current_timestamp), TimeLockError::HeartbeatNotLapsed);
+    require!(
+        inheritance.heirs.iter().any(|heir| heir.heir == ctx.accounts.heir.key()),
+        TimeLockError::Unauthorized
+    );
+    require!(ctx.remaining_accounts.len() == inheritance.heirs.len(), TimeLockError::InvalidHeirs);
+    
+    let total = time_lock_account.sol_balance;
+    let shares = inheritance.shares(total)?;
+    
+    // Balances go to zero before transfer, the account is closed right after
+    time_lock_account.sol_balance = 0;
+    time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(total)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    
+    let time_lock_info = time_lock_account.to_account_info();
+    for ((heir, heir_info), share) in inheritance.heirs.iter().zip(ctx.remaining_accounts).zip(&shares) {
+        require!(heir_info.key() == heir.heir && heir_info.is_writable, TimeLockError::InvalidHeirs);
+        
+        if let Err(e) = transfer_lamports_from_lock(&time_lock_info, heir_info, *share) {
+            critical_msg!("Inheritance transfer to {} failed: {:?}", heir.heir, e);
+            return Err(e);
+        }
+        
+        emit!(WithdrawalEvent {
+            time_lock_account: time_lock_info.key(),
+            owner: time_lock_account.owner,
+            recipient: heir.heir,
+            amount: *share,
+            fee_amount: 0,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: current_timestamp,
+            asset_type: AssetType::Sol,
+        });
+    }
+    
+    time_lock_account.end_operation();
+    
+    let rent_refund = Rent::get()?.minimum_balance(time_lock_info.data_len());
+    
+    event_msg!("Inheritance claimed: {} lamports split across {} heirs", total, shares.len());
+    
+    emit!(AccountClosureEvent {
+        time_lock_account: time_lock_info.key(),
+        owner: time_lock_account.owner,
+        rent_refunded: rent_refund,
+        timestamp: current_timestamp,
+        closure_reason: format!("Inheritance claimed, heartbeat lapsed at {}", inheritance.deadline()),
+    });
+    
+    // Time lock account will be automatically closed by Anchor
+    Ok(())
+}
+
+// ============================================================================
+// CLAIM INHERITANCE (TOKEN)
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ClaimInheritanceToken<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_vault.key() @ TimeLockError::InvalidTokenVault,
+        has_one = original_owner @ TimeLockError::Unauthorized,
+        close = original_owner // Rent goes back to whoever paid it
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Any listed heir, every heir's token account follows in remaining_accounts in listed order
+    pub heir: Signer<'info>,
+
+    /// CHECK: Creator of the lock, receives the lock and vault rent
+    #[account(mut)]
+    pub original_owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_inheritance_token<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimInheritanceToken<'info>>) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Inheritance token claim initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation_with_monitoring(ctx.accounts.heir.key())?;
+    
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let inheritance = time_lock_account.inheritance.clone().ok_or(TimeLockError::NotInheritanceLock)?;
+    require!(inheritance.has_lapsed(current_timestamp), TimeLockError::HeartbeatNotLapsed);
+    require!(
+        inheritance.heirs.iter().any(|heir| heir.heir == ctx.accounts.heir.key()),
+        TimeLockError::Unauthorized
+    );
+    require!(ctx.remaining_accounts.len() == inheritance.heirs.len(), TimeLockError::InvalidHeirs);
+    
+    // Split whatever the vault holds, including tokens sent to it directly
+    let total = ctx.accounts.token_vault.amount;
+    let shares = inheritance.shares(total)?;
+    
+    // Prepare PDA signing seeds
+    let owner_key = time_lock_account.owner;
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    for ((heir, heir_info), share) in inheritance.heirs.iter().zip(ctx.remaining_accounts).zip(&shares) {
+        let heir_token_account = InterfaceAccount::<TokenAccount>::try_from(heir_info)?;
+        require!(heir_token_account.mint == ctx.accounts.token_vault.mint, TimeLockError::InvalidMint);
+        require!(heir_token_account.owner == heir.heir, TimeLockError::InvalidHeirs);
+        
+        if *share == 0 {
+            continue;
+        }
+        
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.token_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: heir_info.clone(),
+            authority: time_lock_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+        
+        if let Err(e) = token_interface::transfer_checked(cpi_ctx, *share, ctx.accounts.mint.decimals) {
+            critical_msg!("Inheritance token transfer to {} failed: {:?}", heir.heir, e);
+            return Err(e);
+        }
+        
+        emit!(WithdrawalEvent {
+            time_lock_account: time_lock_account.key(),
+            owner: owner_key,
+            recipient: heir_info.key(),
+            amount: *share,
+            fee_amount: 0,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: current_timestamp,
+            asset_type: AssetType::Token,
+        });
+    }
+    
+    // Close the emptied vault, its rent goes to the original owner with the lock's
+    let vault_rent = ctx.accounts.token_vault.to_account_info().lamports();
+    let close_vault_accounts = CloseAccount {
+        account: ctx.accounts.token_vault.to_account_info(),
+        destination: ctx.accounts.original_owner.to_account_info(),
+        authority: time_lock_account.to_account_info(),
+    };
+    let close_vault_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_vault_accounts,
+        signer,
+    );
+    token_interface::close_account(close_vault_ctx)?;
+    
+    let account_rent = Rent::get()?.minimum_balance(time_lock_account.to_account_info().data_len());
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(total)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.end_operation();
+    
+    event_msg!("Inheritance claimed: {} tokens split across {} heirs", total, shares.len());
+    
+    emit!(AccountClosureEvent {
+        time_lock_account: time_lock_account.key(),
+        owner: owner_key,
+        rent_refunded: account_rent + vault_rent,
+        timestamp: current_timestamp,
+        closure_reason: format!("Inheritance claimed, heartbeat lapsed at {}", inheritance.deadline()),
+    });
+    
+    // Time lock account will be automatically closed by Anchor
+    Ok(())
+}
@@ -1,9 +1,10 @@
 // handles the initialization of TimeLockAccount
 
 use anchor_lang::prelude::*;
-use crate::state::{TimeLockAccount, AssetType};
+use crate::state::{TimeLockAccount, Config, AssetType, ReleaseSchedule, UnlockTranche, EarlyUnlockPenalty, UnlockCondition, HashLock, Inheritance, Heir};
 use crate::errors::TimeLockError;
 use crate::events::TimeLockCreated;
+use anchor_spl::token_interface::Mint;
 
 #[derive(Accounts)]
 #[instruction(unlock_timestamp: i64, asset_type: AssetType)] // retrieve unlock_timestamp and asset_type
@@ -20,7 +21,21 @@ pub struct Initialize<'info> {
     pub time_lock_account: Account<'info, TimeLockAccount>,
 
     #[account(mut)]
-    pub initializer: Signer<'info>, // require sign the transaction 
+    pub initializer: Signer<'info>, // require sign the transaction
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
 
     pub system_program: Program<'info, System>,
 }
@@ -30,18 +45,718 @@ pub fn initialize(ctx: Context<Initialize>, unlock_timestamp: i64, asset_type: A
     // 🔍 Validate timestamp is in the future
     let current_timestamp = Clock::get()?.unix_timestamp;
     require!(unlock_timestamp > current_timestamp, TimeLockError::InvalidUnlockTime);
+    ctx.accounts.config.validate_lock_duration(unlock_timestamp, current_timestamp)?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_time_lock(
+        &mut ctx.accounts.time_lock_account,
+        time_lock_key,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        None,
+        unlock_timestamp,
+        UnlockCondition::Timestamp,
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        ReleaseSchedule::Cliff,
+        current_timestamp,
+    )
+}
+
+// ============================================================================
+// ID-BASED LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, unlock_timestamp: i64, asset_type: AssetType)]
+pub struct InitializeWithId<'info> {
+    // The PDA is derived from a caller-chosen lock_id instead of the unlock timestamp,
+    // so one owner can hold any number of locks sharing the same unlock second
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_with_id(
+    ctx: Context<InitializeWithId>,
+    lock_id: u64,
+    unlock_timestamp: i64,
+    asset_type: AssetType,
+) -> Result<()> {
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(unlock_timestamp > current_timestamp, TimeLockError::InvalidUnlockTime);
+    ctx.accounts.config.validate_lock_duration(unlock_timestamp, current_timestamp)?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_time_lock(
+        &mut ctx.accounts.time_lock_account,
+        time_lock_key,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        unlock_timestamp,
+        UnlockCondition::Timestamp,
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        ReleaseSchedule::Cliff,
+        current_timestamp,
+    )
+}
+
+// ============================================================================
+// VESTING LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(start_ts: i64, cliff_ts: i64, end_ts: i64, asset_type: AssetType, revocable: bool)]
+pub struct InitializeVesting<'info> {
+    // The vesting end doubles as unlock_timestamp, so it is used for PDA derivation
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [b"time_lock", initializer.key().as_ref(), &end_ts.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_vesting(
+    ctx: Context<InitializeVesting>,
+    start_ts: i64,
+    cliff_ts: i64,
+    end_ts: i64,
+    asset_type: AssetType,
+    revocable: bool,
+) -> Result<()> {
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_scheduled_lock(
+        &mut ctx.accounts.time_lock_account,
+        &ctx.accounts.config,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        None,
+        end_ts,
+        ReleaseSchedule::Linear { start_ts, cliff_ts, end_ts },
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        revocable,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, start_ts: i64, cliff_ts: i64, end_ts: i64, asset_type: AssetType, revocable: bool)]
+pub struct InitializeVestingWithId<'info> {
+    // Id-addressed so several grants to the same beneficiary may end in the same second
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_vesting_with_id(
+    ctx: Context<InitializeVestingWithId>,
+    lock_id: u64,
+    start_ts: i64,
+    cliff_ts: i64,
+    end_ts: i64,
+    asset_type: AssetType,
+    revocable: bool,
+) -> Result<()> {
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_scheduled_lock(
+        &mut ctx.accounts.time_lock_account,
+        &ctx.accounts.config,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        end_ts,
+        ReleaseSchedule::Linear { start_ts, cliff_ts, end_ts },
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        revocable,
+    )
+}
+
+// ============================================================================
+// MULTI-TRANCHE LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(tranches: Vec<UnlockTranche>, asset_type: AssetType, revocable: bool)]
+pub struct InitializeTranches<'info> {
+    // The final tranche doubles as unlock_timestamp, so it is used for PDA derivation
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [
+            b"time_lock",
+            initializer.key().as_ref(),
+            &ReleaseSchedule::final_tranche_seed(&tranches),
+        ],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_tranches(
+    ctx: Context<InitializeTranches>,
+    tranches: Vec<UnlockTranche>,
+    asset_type: AssetType,
+    revocable: bool,
+) -> Result<()> {
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_scheduled_lock(
+        &mut ctx.accounts.time_lock_account,
+        &ctx.accounts.config,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        None,
+        ReleaseSchedule::final_tranche_timestamp(&tranches),
+        ReleaseSchedule::Tranches(tranches),
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        revocable,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, tranches: Vec<UnlockTranche>, asset_type: AssetType, revocable: bool)]
+pub struct InitializeTranchesWithId<'info> {
+    // Id-addressed so several tranche locks may share their final tranche timestamp
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_tranches_with_id(
+    ctx: Context<InitializeTranchesWithId>,
+    lock_id: u64,
+    tranches: Vec<UnlockTranche>,
+    asset_type: AssetType,
+    revocable: bool,
+) -> Result<()> {
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_scheduled_lock(
+        &mut ctx.accounts.time_lock_account,
+        &ctx.accounts.config,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        ReleaseSchedule::final_tranche_timestamp(&tranches),
+        ReleaseSchedule::Tranches(tranches),
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        revocable,
+    )
+}
+
+// ============================================================================
+// SOFT LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, unlock_timestamp: i64, asset_type: AssetType, penalty_bps: u16, linear_decay: bool)]
+pub struct InitializeSoftLock<'info> {
+    // Soft locks use the id scheme so several savings goals can share an unlock second
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Receives early withdrawal penalties, any key chosen by the initializer
+    pub penalty_recipient: UncheckedAccount<'info>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_soft_lock(
+    ctx: Context<InitializeSoftLock>,
+    lock_id: u64,
+    unlock_timestamp: i64,
+    asset_type: AssetType,
+    penalty_bps: u16,
+    linear_decay: bool,
+) -> Result<()> {
+    // Early withdrawals only pay out SOL or a single token, a basket would be stuck
+    require!(asset_type != AssetType::Basket, TimeLockError::UnsupportedBasketLock);
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(unlock_timestamp > current_timestamp, TimeLockError::InvalidUnlockTime);
+    ctx.accounts.config.validate_lock_duration(unlock_timestamp, current_timestamp)?;
+    
+    let early_unlock = EarlyUnlockPenalty {
+        penalty_bps,
+        linear_decay,
+        recipient: ctx.accounts.penalty_recipient.key(),
+        start_ts: current_timestamp,
+    };
+    early_unlock.validate(unlock_timestamp)?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_time_lock(
+        &mut ctx.accounts.time_lock_account,
+        time_lock_key,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        unlock_timestamp,
+        UnlockCondition::Timestamp,
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        ReleaseSchedule::Cliff,
+        current_timestamp,
+    )?;
+    
+    // Early exit is allowed at a price instead of failing with TimeLockNotExpired
+    msg!("💸 Early unlock penalty: {} bps, linear decay: {}", penalty_bps, linear_decay);
+    ctx.accounts.time_lock_account.early_unlock = Some(early_unlock);
+    
+    Ok(())
+}
+
+// ============================================================================
+// SLOT / EPOCH LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, unlock_condition: UnlockCondition, asset_type: AssetType)]
+pub struct InitializeWithCondition<'info> {
+    // There is no unlock timestamp to derive from, so these locks always use the id scheme
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Optional key allowed to withdraw, defaults to the initializer when omitted
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_with_condition(
+    ctx: Context<InitializeWithCondition>,
+    lock_id: u64,
+    unlock_condition: UnlockCondition,
+    asset_type: AssetType,
+) -> Result<()> {
+    // Timestamp locks are created through initialize or initialize_with_id
+    let clock = Clock::get()?;
+    unlock_condition.validate(&clock)?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let owner = ctx.accounts.initializer.key();
+    let beneficiary = ctx.accounts.beneficiary.as_ref().map_or(owner, |beneficiary| beneficiary.key());
+    init_time_lock(
+        &mut ctx.accounts.time_lock_account,
+        time_lock_key,
+        owner,
+        beneficiary,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        0, // no wall-clock deadline, is_unlocked only checks the slot or epoch
+        unlock_condition,
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        ReleaseSchedule::Cliff,
+        clock.unix_timestamp,
+    )
+}
+
+// ============================================================================
+// HASH-TIME-LOCK (HTLC)
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, hash: [u8; 32], deadline: i64, asset_type: AssetType)]
+pub struct InitializeHtlc<'info> {
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Also the beneficiary, reclaims through the regular withdrawals after the deadline
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    /// CHECK: Paid on a valid preimage before the deadline, any key chosen by the initializer
+    pub counterparty: UncheckedAccount<'info>,
 
-    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (lock duration bounds, pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_htlc(
+    ctx: Context<InitializeHtlc>,
+    lock_id: u64,
+    hash: [u8; 32],
+    deadline: i64,
+    asset_type: AssetType,
+) -> Result<()> {
+    // The preimage claim has no basket path
+    require!(asset_type != AssetType::Basket, TimeLockError::UnsupportedBasketLock);
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    require!(deadline > current_timestamp, TimeLockError::InvalidUnlockTime);
+    ctx.accounts.config.validate_lock_duration(deadline, current_timestamp)?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let owner = ctx.accounts.initializer.key();
+    init_time_lock(
+        &mut ctx.accounts.time_lock_account,
+        time_lock_key,
+        owner,
+        owner,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        deadline,
+        UnlockCondition::Timestamp,
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        ReleaseSchedule::Cliff,
+        current_timestamp,
+    )?;
     
+    let counterparty = ctx.accounts.counterparty.key();
+    msg!("🔐 Hashlock counterparty: {}", counterparty);
+    ctx.accounts.time_lock_account.hashlock = Some(HashLock { hash, counterparty });
+    
+    Ok(())
+}
+
+// ============================================================================
+// DEAD-MAN'S-SWITCH INHERITANCE LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(lock_id: u64, heartbeat_interval: i64, heirs: Vec<Heir>, asset_type: AssetType)]
+pub struct InitializeInheritance<'info> {
+    #[account(
+        init,
+        payer = initializer,
+        space = TimeLockAccount::INIT_SPACE,
+        seeds = [TimeLockAccount::LOCK_ID_SEED_PREFIX, initializer.key().as_ref(), &lock_id.to_le_bytes()],
+        bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Also the beneficiary, free to withdraw at any time while alive
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+
+    // Token locks fix their mint here so no depositor can pick it, omitted for other asset types
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    // Program-wide policy (pause switch)
+    #[account(
+        seeds = [Config::SEED],
+        bump = config.bump,
+        constraint = !config.paused @ TimeLockError::ProgramPaused,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_inheritance(
+    ctx: Context<InitializeInheritance>,
+    lock_id: u64,
+    heartbeat_interval: i64,
+    heirs: Vec<Heir>,
+    asset_type: AssetType,
+) -> Result<()> {
+    // Heir claims have no basket path
+    require!(asset_type != AssetType::Basket, TimeLockError::UnsupportedBasketLock);
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    let inheritance = Inheritance {
+        heartbeat_interval,
+        last_heartbeat: current_timestamp,
+        heirs,
+    };
+    inheritance.validate()?;
+    
+    let time_lock_key = ctx.accounts.time_lock_account.key();
+    let owner = ctx.accounts.initializer.key();
+    init_time_lock(
+        &mut ctx.accounts.time_lock_account,
+        time_lock_key,
+        owner,
+        owner,
+        ctx.bumps.time_lock_account,
+        Some(lock_id),
+        current_timestamp, // already unlocked, the owner's regular withdrawals work right away
+        UnlockCondition::Timestamp,
+        asset_type,
+        ctx.accounts.mint.as_ref().map(|mint| mint.key()),
+        ReleaseSchedule::Cliff,
+        current_timestamp,
+    )?;
+    
+    msg!("💓 Heartbeat interval: {} seconds, heirs: {}", heartbeat_interval, inheritance.heirs.len());
+    ctx.accounts.time_lock_account.inheritance = Some(inheritance);
+    
+    Ok(())
+}
+
+// Shared setup of vesting and tranche locks, the schedule's end doubles as unlock_timestamp
+#[allow(clippy::too_many_arguments)]
+fn init_scheduled_lock(
+    time_lock_account: &mut Account<TimeLockAccount>,
+    config: &Config,
+    owner: Pubkey,
+    beneficiary: Pubkey,
+    bump: u8,
+    lock_id: Option<u64>,
+    unlock_timestamp: i64,
+    schedule: ReleaseSchedule,
+    asset_type: AssetType,
+    mint: Option<Pubkey>,
+    revocable: bool,
+) -> Result<()> {
+    // Revoking a self-lock would just be an early withdrawal that skips the schedule
+    require!(!revocable || beneficiary != owner, TimeLockError::SelfGrantNotRevocable);
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    schedule.validate(current_timestamp)?;
+    config.validate_lock_duration(unlock_timestamp, current_timestamp)?;
+    
+    let time_lock_key = time_lock_account.key();
+    init_time_lock(
+        time_lock_account,
+        time_lock_key,
+        owner,
+        beneficiary,
+        bump,
+        lock_id,
+        unlock_timestamp,
+        UnlockCondition::Timestamp,
+        asset_type,
+        mint,
+        schedule,
+        current_timestamp,
+    )?;
+    
+    // Grants may let the owner claw back the unvested remainder
+    time_lock_account.revocable = revocable;
+    msg!("↩️ Revocable: {}", revocable);
+    
+    Ok(())
+}
+
+// Shared account setup for every lock flavour
+#[allow(clippy::too_many_arguments)]
+fn init_time_lock(
+    time_lock_account: &mut TimeLockAccount,
+    time_lock_key: Pubkey,
+    owner: Pubkey,
+    beneficiary: Pubkey,
+    bump: u8,
+    lock_id: Option<u64>,
+    unlock_timestamp: i64,
+    unlock_condition: UnlockCondition,
+    asset_type: AssetType,
+    mint: Option<Pubkey>,
+    schedule: ReleaseSchedule,
+    current_timestamp: i64,
+) -> Result<()> {
     msg!("🏗️ Initializing time lock account...");
     
+    // Only token locks carry a mint, and they must name it up front
+    require!(mint.is_some() == (asset_type == AssetType::Token), TimeLockError::InvalidMint);
+    
     // Initialize account data
-    time_lock_account.owner = ctx.accounts.initializer.key(); // store public key
+    time_lock_account.owner = owner; // store public key
+    time_lock_account.beneficiary = beneficiary; // only signer allowed to withdraw
     time_lock_account.unlock_timestamp = unlock_timestamp;
-    time_lock_account.bump = ctx.bumps.time_lock_account; // use auto-generated bump
+    time_lock_account.seed_timestamp = unlock_timestamp; // never changes, keeps the PDA derivable
+    time_lock_account.lock_id = lock_id;
+    time_lock_account.bump = bump; // use auto-generated bump
     time_lock_account.asset_type = asset_type.clone(); // use provided asset_type
     time_lock_account.amount = 0;
     time_lock_account.token_vault = Pubkey::default(); // will be set if SPL
+    time_lock_account.mint = mint.unwrap_or_default(); // fixed for the lifetime of a token lock
     
     // 🔒 Initialize reentrancy protection
     time_lock_account.is_initialized = true;
@@ -49,19 +764,44 @@ pub fn initialize(ctx: Context<Initialize>, unlock_timestamp: i64, asset_type: A
     time_lock_account.spl_token_account = None;
     time_lock_account.is_processing = false; // Critical: Initialize as false
     
+    time_lock_account.schedule = schedule.clone();
+    time_lock_account.withdrawn_amount = 0;
+    time_lock_account.revocable = false;
+    time_lock_account.original_owner = owner; // seeds keep using the creator after ownership transfers
+    time_lock_account.pending_owner = None;
+    time_lock_account.multisig = None;
+    time_lock_account.guardians = None;
+    time_lock_account.pending_recovery = None;
+    time_lock_account.early_unlock = None;
+    time_lock_account.unlock_condition = unlock_condition;
+    time_lock_account.hashlock = None;
+    time_lock_account.inheritance = None;
+    time_lock_account.basket_assets = Vec::new();
+    
     msg!("✅ Time lock account initialized successfully");
     msg!("📍 Owner: {}", time_lock_account.owner);
+    msg!("🎯 Beneficiary: {}", time_lock_account.beneficiary);
     msg!("⏰ Unlock timestamp: {}", time_lock_account.unlock_timestamp);
+    msg!("🔓 Unlock condition: {:?}", time_lock_account.unlock_condition);
+    msg!("🆔 Lock id: {:?}", time_lock_account.lock_id);
     msg!("💎 Asset type: {:?}", time_lock_account.asset_type);
+    if let Some(mint) = mint {
+        msg!("🪙 Locked mint: {}", mint);
+    }
+    msg!("📈 Schedule: {:?}", time_lock_account.schedule);
     
     // Emit creation event
     emit!(TimeLockCreated {
-        time_lock_account: ctx.accounts.time_lock_account.key(),
-        owner: ctx.accounts.initializer.key(),
+        time_lock_account: time_lock_key,
+        owner,
+        beneficiary,
+        lock_id,
         unlock_timestamp,
+        unlock_condition,
         asset_type,
+        schedule,
         current_timestamp,
     });
-
+    
     Ok(())
-}
\ No newline at end of file
+}
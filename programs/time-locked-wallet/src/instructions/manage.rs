@@ -0,0 +1,515 @@
+use anchor_lang::prelude::*;
+use crate::state::{TimeLockAccount, LegacyTimeLockAccount, Config, ReleaseSchedule, MultisigConfig, GuardianSet, PendingRecovery, UnlockCondition};
+use crate::errors::TimeLockError;
+use crate::events::{
+    LockExtended, OwnershipTransferProposed, OwnershipTransferred, BeneficiaryRotated, MultisigConfigured, LegacyAccountMigrated,
+    GuardiansConfigured, RecoveryInitiated, RecoveryCancelled, RecoveryCompleted,
+};
+use crate::{debug_msg, event_msg};
+use anchor_lang::system_program;
+use anchor_spl::token_interface::TokenAccount;
+
+// ============================================================================
+// EXTEND LOCK
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(new_unlock_timestamp: i64)]
+pub struct ExtendLock<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Extending only delays the beneficiary's access, so it is the beneficiary's call
+    pub beneficiary: Signer<'info>,
+
+    // Program-wide policy (lock duration bounds)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+pub fn extend_lock(ctx: Context<ExtendLock>, new_unlock_timestamp: i64) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Lock extension requested for account: {}", time_lock_account.key());
+    
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    // Vesting and tranche schedules carry their own dates and are not shifted here
+    require!(
+        time_lock_account.schedule == ReleaseSchedule::Cliff,
+        TimeLockError::InvalidLockExtension
+    );
+    require!(
+        time_lock_account.unlock_condition == UnlockCondition::Timestamp,
+        TimeLockError::InvalidUnlockCondition
+    );
+    
+    // Only allow re-committing for longer, never shortening the lock
+    require!(
+        new_unlock_timestamp > time_lock_account.unlock_timestamp,
+        TimeLockError::InvalidLockExtension
+    );
+    require!(new_unlock_timestamp > current_timestamp, TimeLockError::InvalidUnlockTime);
+    ctx.accounts.config.validate_lock_duration(new_unlock_timestamp, current_timestamp)?;
+    
+    let previous_unlock_timestamp = time_lock_account.unlock_timestamp;
+    time_lock_account.unlock_timestamp = new_unlock_timestamp;
+    
+    event_msg!("Lock extended from {} to {}", previous_unlock_timestamp, new_unlock_timestamp);
+    
+    emit!(LockExtended {
+        time_lock_account: time_lock_account.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        previous_unlock_timestamp,
+        new_unlock_timestamp,
+        timestamp: current_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// TWO-STEP OWNERSHIP TRANSFER
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct ProposeOwner<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Pubkey) -> Result<()> {
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    require!(
+        new_owner != Pubkey::default() && new_owner != time_lock_account.owner,
+        TimeLockError::InvalidNewOwner
+    );
+    
+    // A new proposal replaces any earlier one that was never accepted
+    time_lock_account.pending_owner = Some(new_owner);
+    
+    event_msg!("Ownership transfer proposed: {} -> {}", time_lock_account.owner, new_owner);
+    
+    emit!(OwnershipTransferProposed {
+        time_lock_account: time_lock_account.key(),
+        owner: ctx.accounts.owner.key(),
+        pending_owner: new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        constraint = time_lock_account.pending_owner == Some(new_owner.key()) @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    pub new_owner: Signer<'info>,
+}
+
+pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let new_owner = ctx.accounts.new_owner.key();
+    let previous_owner = time_lock_account.owner;
+    
+    debug_msg!("Ownership transfer accepted for account: {}", time_lock_account.key());
+    
+    let beneficiary_moved = time_lock_account.rotate_owner(new_owner);
+    
+    event_msg!("Ownership transferred: {} -> {}, withdrawal rights moved: {}", 
+               previous_owner, new_owner, beneficiary_moved);
+    
+    emit!(OwnershipTransferred {
+        time_lock_account: time_lock_account.key(),
+        previous_owner,
+        new_owner,
+        beneficiary: time_lock_account.beneficiary,
+        withdrawal_rights_moved: beneficiary_moved,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// BENEFICIARY ROTATION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct RotateBeneficiary<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = new_beneficiary.key() != Pubkey::default() @ TimeLockError::InvalidNewOwner,
+        constraint = new_beneficiary.key() != time_lock_account.beneficiary @ TimeLockError::InvalidNewOwner,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    pub beneficiary: Signer<'info>,
+
+    // Co-signs so withdrawal rights can never move to a key nobody controls
+    pub new_beneficiary: Signer<'info>,
+}
+
+pub fn rotate_beneficiary(ctx: Context<RotateBeneficiary>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let previous_beneficiary = time_lock_account.beneficiary;
+    let new_beneficiary = ctx.accounts.new_beneficiary.key();
+    
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    time_lock_account.beneficiary = new_beneficiary;
+    
+    event_msg!("Beneficiary rotated: {} -> {}", previous_beneficiary, new_beneficiary);
+    
+    emit!(BeneficiaryRotated {
+        time_lock_account: time_lock_account.key(),
+        owner: time_lock_account.owner,
+        previous_beneficiary,
+        new_beneficiary,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// MULTISIG CONFIGURATION
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(signers: Vec<Pubkey>, threshold: u8)]
+pub struct SetMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    pub owner: Signer<'info>,
+
+    // Co-signs so the owner alone cannot put a signer set in front of someone else's funds
+    pub beneficiary: Signer<'info>,
+}
+
+pub fn set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    // Changing an existing signer set needs that set's approval first
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    // An empty signer list removes the multisig requirement
+    time_lock_account.multisig = if signers.is_empty() {
+        None
+    } else {
+        let multisig = MultisigConfig { signers: signers.clone(), threshold };
+        multisig.validate()?;
+        Some(multisig)
+    };
+    // Raising the multisig above the guardian threshold would let recovery bypass it
+    time_lock_account.validate_recovery_threshold()?;
+    
+    event_msg!("Multisig configured: {} of {} signers", threshold, signers.len());
+    
+    emit!(MultisigConfigured {
+        time_lock_account: time_lock_account.key(),
+        owner: ctx.accounts.owner.key(),
+        signers,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// LEGACY ACCOUNT MIGRATION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct MigrateLegacyAccount<'info> {
+    /// CHECK: Pre-upgrade layout that `Account` can no longer deserialize, its size,
+    /// discriminator and PDA are verified in the handler
+    #[account(mut, owner = crate::ID @ TimeLockError::NotLegacyAccount)]
+    pub time_lock_account: UncheckedAccount<'info>,
+
+    // Pays the rent of the larger layout, anyone may since the migrated values are fixed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // Vault of a legacy token lock that already holds a deposit, its mint becomes the lock's mint
+    pub token_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_legacy_account(ctx: Context<MigrateLegacyAccount>) -> Result<()> {
+    let time_lock_info = ctx.accounts.time_lock_account.to_account_info();
+    require!(
+        time_lock_info.data_len() == LegacyTimeLockAccount::SPACE,
+        TimeLockError::NotLegacyAccount
+    );
+    
+    let legacy = {
+        let data = time_lock_info.try_borrow_data()?;
+        require!(data.starts_with(TimeLockAccount::DISCRIMINATOR), TimeLockError::NotLegacyAccount);
+        LegacyTimeLockAccount::deserialize(&mut &data[8..])?
+    };
+    
+    // Legacy PDAs always used the timestamp scheme with the unlock time set at creation
+    let expected_address = Pubkey::create_program_address(
+        &[
+            TimeLockAccount::SEED_PREFIX,
+            legacy.owner.as_ref(),
+            &legacy.unlock_timestamp.to_le_bytes(),
+            &[legacy.bump],
+        ],
+        ctx.program_id,
+    ).map_err(|_| error!(TimeLockError::NotLegacyAccount))?;
+    require_keys_eq!(expected_address, time_lock_info.key(), TimeLockError::NotLegacyAccount);
+    
+    // A token lock without a vault never got a deposit and can only be closed
+    let mint = if legacy.token_vault == Pubkey::default() {
+        Pubkey::default()
+    } else {
+        let token_vault = ctx.accounts.token_vault.as_ref().ok_or(TimeLockError::InvalidTokenVault)?;
+        require_keys_eq!(token_vault.key(), legacy.token_vault, TimeLockError::InvalidTokenVault);
+        token_vault.mint
+    };
+    
+    // Only the rent difference is added, lamports above the old reserve stay the lock's principal
+    let rent = Rent::get()?;
+    let rent_top_up = rent.minimum_balance(TimeLockAccount::INIT_SPACE)
+        .saturating_sub(rent.minimum_balance(LegacyTimeLockAccount::SPACE));
+    if rent_top_up > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: time_lock_info.clone(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, rent_top_up)?;
+    }
+    time_lock_info.resize(TimeLockAccount::INIT_SPACE)?;
+    
+    let owner = legacy.owner;
+    let migrated = legacy.migrate(mint);
+    migrated.try_serialize(&mut &mut time_lock_info.try_borrow_mut_data()?[..])?;
+    
+    event_msg!("Legacy account {} migrated, {} lamports rent top-up", time_lock_info.key(), rent_top_up);
+    
+    emit!(LegacyAccountMigrated {
+        time_lock_account: time_lock_info.key(),
+        owner,
+        payer: ctx.accounts.payer.key(),
+        rent_top_up,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// ============================================================================
+// GUARDIAN RECOVERY
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(guardians: Vec<Pubkey>, threshold: u8)]
+pub struct SetGuardians<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn set_guardians(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    ctx.accounts.time_lock_account.require_multisig_approval(&ctx.accounts.owner.key(), ctx.remaining_accounts)?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    // An empty guardian list removes social recovery
+    time_lock_account.guardians = if guardians.is_empty() {
+        None
+    } else {
+        let guardian_set = GuardianSet { guardians: guardians.clone(), threshold };
+        guardian_set.validate()?;
+        Some(guardian_set)
+    };
+    time_lock_account.validate_recovery_threshold()?;
+    
+    // A recovery started by the previous guardian set no longer stands
+    time_lock_account.pending_recovery = None;
+    
+    event_msg!("Guardians configured: {} of {} guardians", threshold, guardians.len());
+    
+    emit!(GuardiansConfigured {
+        time_lock_account: time_lock_account.key(),
+        owner: ctx.accounts.owner.key(),
+        guardians,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(new_owner: Pubkey)]
+pub struct InitiateRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Any guardian; co-guardians sign as remaining accounts
+    pub guardian: Signer<'info>,
+}
+
+pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Recovery initiated for account: {}", time_lock_account.key());
+    
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    
+    let guardian_set = time_lock_account.guardians.as_ref().ok_or(TimeLockError::NoGuardians)?;
+    guardian_set.require_approval(&ctx.accounts.guardian.key(), ctx.remaining_accounts)?;
+    
+    require!(
+        new_owner != Pubkey::default() && new_owner != time_lock_account.owner,
+        TimeLockError::InvalidNewOwner
+    );
+    
+    // A new initiation replaces an earlier one and restarts the delay
+    let recovery = PendingRecovery { new_owner, initiated_at: current_timestamp };
+    let executable_at = recovery.executable_at();
+    time_lock_account.pending_recovery = Some(recovery);
+    
+    event_msg!("Recovery to {} executable at {}", new_owner, executable_at);
+    
+    emit!(RecoveryInitiated {
+        time_lock_account: time_lock_account.key(),
+        initiated_by: ctx.accounts.guardian.key(),
+        current_owner: time_lock_account.owner,
+        new_owner,
+        executable_at,
+        timestamp: current_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = owner @ TimeLockError::Unauthorized,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Holding the owner key proves it was not lost, no multisig needed to veto
+    pub owner: Signer<'info>,
+}
+
+pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    
+    let recovery = time_lock_account.pending_recovery.take().ok_or(TimeLockError::NoPendingRecovery)?;
+    
+    event_msg!("Recovery to {} cancelled by owner", recovery.new_owner);
+    
+    emit!(RecoveryCancelled {
+        time_lock_account: time_lock_account.key(),
+        owner: ctx.accounts.owner.key(),
+        cancelled_new_owner: recovery.new_owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompleteRecovery<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Anyone can execute a recovery once its delay has passed
+    pub caller: Signer<'info>,
+}
+
+pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    let recovery = time_lock_account.pending_recovery.clone().ok_or(TimeLockError::NoPendingRecovery)?;
+    require!(
+        current_timestamp >= recovery.executable_at(),
+        TimeLockError::RecoveryDelayNotElapsed
+    );
+    
+    let previous_owner = time_lock_account.owner;
+    time_lock_account.rotate_owner(recovery.new_owner);
+    time_lock_account.pending_recovery = None;
+    
+    // The old signer set most likely includes the lost key, the new owner can configure a fresh one
+    // Safe to drop since set_guardians and set_multisig keep the guardian threshold at or above the multisig's
+    time_lock_account.multisig = None;
+    
+    event_msg!("Recovery completed: {} -> {}", previous_owner, recovery.new_owner);
+    
+    emit!(RecoveryCompleted {
+        time_lock_account: time_lock_account.key(),
+        previous_owner,
+        new_owner: recovery.new_owner,
+        beneficiary: time_lock_account.beneficiary,
+        timestamp: current_timestamp,
+    });
+    
+    Ok(())
+}
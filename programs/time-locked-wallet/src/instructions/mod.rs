@@ -6,8 +6,16 @@ pub mod initialize;
 pub mod deposit;    // Consolidated SOL + Token deposit
 pub mod withdraw;   // Consolidated SOL + Token withdraw
 pub mod close;      // Account closure and cleanup
+pub mod claim;      // Vesting and hashlock claims for SOL + Token
+pub mod manage;     // Lock management (extension, ownership, multisig, recovery)
+pub mod inheritance; // Dead-man's-switch heartbeat and heir claims
+pub mod config;     // Program-wide policy managed by the admin
 
 pub use initialize::*;
 pub use deposit::*;
 pub use withdraw::*;
-pub use close::*;
\ No newline at end of file
+pub use close::*;
+pub use claim::*;
+pub use manage::*;
+pub use inheritance::*;
+pub use config::*;
\ No newline at end of file
@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::{TimeLockAccount, AssetType};
+use crate::state::{TimeLockAccount, Config, AssetType};
 use crate::errors::TimeLockError;
-use crate::events::WithdrawalEvent;
+use crate::events::{WithdrawalEvent, EarlyWithdrawalEvent, AccountClosureEvent};
+use crate::instructions::config::{require_treasury, transfer_fee_lamports};
 use crate::{debug_msg, critical_msg, event_msg};
-use anchor_spl::token::{Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked, CloseAccount};
 
 // ============================================================================
 // SOL WITHDRAW
@@ -13,15 +14,23 @@ use anchor_spl::token::{Token, TokenAccount, Transfer};
 pub struct WithdrawSol<'info> {
     #[account(
         mut, 
-        seeds = [b"time_lock", owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
-        has_one = owner @ TimeLockError::Unauthorized,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
         constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
     
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub beneficiary: Signer<'info>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Fee destination, only required while the config charges fees
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -30,26 +39,50 @@ pub struct WithdrawSol<'info> {
 pub struct WithdrawAndCloseSol<'info> {
     #[account(
         mut, 
-        seeds = [b"time_lock", owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
         has_one = owner @ TimeLockError::Unauthorized,
         constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType,
-        close = owner
+        close = owner // Rent goes back to whoever created and paid for the lock
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
     
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub beneficiary: Signer<'info>,
+    
+    /// CHECK: Rent refund destination, validated by has_one against the stored owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Fee destination, only required while the config charges fees
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
     let time_lock_account = &mut ctx.accounts.time_lock_account;
     
     debug_msg!("Withdrawal initiated for account: {}", ctx.accounts.time_lock_account.key());
     
-    time_lock_account.start_operation_with_monitoring(ctx.accounts.owner.key())?;
+    time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
     time_lock_account.validate_sol_withdrawal()?;
     
     let amount_to_transfer = time_lock_account.sol_balance;
@@ -57,39 +90,36 @@ pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
     
     debug_msg!("Withdrawal amount: {} lamports", amount_to_transfer);
     
+    let fee_amount = Config::bps_fee(amount_to_transfer, ctx.accounts.config.withdrawal_fee_bps);
+    
     time_lock_account.sol_balance = 0;
     time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(amount_to_transfer)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
     
-    let result = (|| -> Result<()> {
-        let time_lock_lamports = time_lock_account.to_account_info().lamports();
-        let rent_exempt_amount = Rent::get()?.minimum_balance(
-            time_lock_account.to_account_info().data_len()
-        );
-        
-        let available_lamports = time_lock_lamports.saturating_sub(rent_exempt_amount);
-        require!(
-            available_lamports >= amount_to_transfer,
-            TimeLockError::InsufficientFunds
-        );
-        
-        **time_lock_account.to_account_info().try_borrow_mut_lamports()? -= amount_to_transfer;
-        **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount_to_transfer;
-        
-        Ok(())
-    })();
+    let result = transfer_lamports_with_fee(
+        &time_lock_account.to_account_info(),
+        &ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        amount_to_transfer,
+        fee_amount,
+    );
     
     time_lock_account.end_operation();
     
     match result {
         Ok(_) => {
             event_msg!("Withdrawal completed: {} lamports to {}", 
-                      amount_to_transfer, ctx.accounts.owner.key());
+                      amount_to_transfer - fee_amount, ctx.accounts.beneficiary.key());
             
             emit!(WithdrawalEvent {
                 time_lock_account: ctx.accounts.time_lock_account.key(),
-                owner: ctx.accounts.owner.key(),
-                recipient: ctx.accounts.owner.key(),
-                amount: amount_to_transfer,
+                owner: ctx.accounts.time_lock_account.owner,
+                recipient: ctx.accounts.beneficiary.key(),
+                amount: amount_to_transfer - fee_amount,
+                fee_amount,
+                flat_fee_lamports,
                 remaining_balance: 0,
                 timestamp: Clock::get()?.unix_timestamp,
                 asset_type: AssetType::Sol,
@@ -101,21 +131,157 @@ pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
             critical_msg!("Withdrawal failed, rolling back: {:?}", e);
             time_lock_account.sol_balance = amount_to_transfer;
             time_lock_account.amount = amount_to_transfer;
-            Err(e.into())
+            time_lock_account.withdrawn_amount -= amount_to_transfer;
+            Err(e)
+        }
+    }
+}
+
+pub fn withdraw_sol_partial(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    let recipient = ctx.accounts.beneficiary.to_account_info();
+    let treasury = ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info());
+    release_sol(
+        &mut ctx.accounts.time_lock_account,
+        ctx.accounts.beneficiary.key(),
+        &recipient,
+        amount,
+        Config::bps_fee(amount, ctx.accounts.config.withdrawal_fee_bps),
+        treasury,
+        flat_fee_lamports,
+    )
+}
+
+pub fn withdraw_sol_to(ctx: Context<WithdrawSolTo>, amount: u64) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    let recipient = ctx.accounts.recipient.to_account_info();
+    let treasury = ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info());
+    release_sol(
+        &mut ctx.accounts.time_lock_account,
+        ctx.accounts.beneficiary.key(),
+        &recipient,
+        amount,
+        Config::bps_fee(amount, ctx.accounts.config.withdrawal_fee_bps),
+        treasury,
+        flat_fee_lamports,
+    )
+}
+
+// Releases `amount` lamports, less `fee_amount` for the treasury, to `recipient`, leaving the remainder locked
+fn release_sol<'info>(
+    time_lock_account: &mut Account<'info, TimeLockAccount>,
+    caller: Pubkey,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+    fee_amount: u64,
+    treasury: Option<AccountInfo<'info>>,
+    flat_fee_lamports: u64,
+) -> Result<()> {
+    debug_msg!("Partial withdrawal initiated for account: {}", time_lock_account.key());
+    
+    time_lock_account.start_operation_with_monitoring(caller)?;
+    time_lock_account.validate_partial_sol_withdrawal(amount)?;
+    
+    let previous_sol_balance = time_lock_account.sol_balance;
+    let previous_amount = time_lock_account.amount;
+    let previous_withdrawn = time_lock_account.withdrawn_amount;
+    
+    debug_msg!("Partial withdrawal amount: {} of {} lamports", amount, previous_sol_balance);
+    
+    // Update balances before transfer, the remainder stays locked
+    time_lock_account.sol_balance = previous_sol_balance
+        .checked_sub(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.amount = previous_amount
+        .checked_sub(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.withdrawn_amount = previous_withdrawn
+        .checked_add(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    let remaining_balance = time_lock_account.sol_balance;
+    
+    let result = transfer_lamports_with_fee(
+        &time_lock_account.to_account_info(),
+        recipient,
+        treasury,
+        amount,
+        fee_amount,
+    );
+    
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            event_msg!("Partial withdrawal completed: {} lamports to {}, {} lamports remain locked", 
+                      amount - fee_amount, recipient.key(), remaining_balance);
+            
+            emit!(WithdrawalEvent {
+                time_lock_account: time_lock_account.key(),
+                owner: time_lock_account.owner,
+                recipient: recipient.key(),
+                amount: amount - fee_amount,
+                fee_amount,
+                flat_fee_lamports,
+                remaining_balance,
+                timestamp: Clock::get()?.unix_timestamp,
+                asset_type: AssetType::Sol,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Partial withdrawal failed, rolling back: {:?}", e);
+            time_lock_account.sol_balance = previous_sol_balance;
+            time_lock_account.amount = previous_amount;
+            time_lock_account.withdrawn_amount = previous_withdrawn;
+            Err(e)
         }
     }
 }
 
 pub fn withdraw_and_close_sol(ctx: Context<WithdrawAndCloseSol>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
     let time_lock_account = &mut ctx.accounts.time_lock_account;
     
     debug_msg!("Withdrawal and closure initiated for account: {}", ctx.accounts.time_lock_account.key());
     
-    time_lock_account.start_operation_with_monitoring(ctx.accounts.owner.key())?;
+    time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
     time_lock_account.validate_sol_withdrawal()?;
     
     let amount_to_transfer = time_lock_account.sol_balance;
     require!(amount_to_transfer > 0, TimeLockError::InsufficientBalance);
+    let fee_amount = Config::bps_fee(amount_to_transfer, ctx.accounts.config.withdrawal_fee_bps);
+    let treasury = ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info());
     
     // Calculate rent that will be refunded
     let rent_refund = Rent::get()?.minimum_balance(
@@ -139,25 +305,31 @@ pub fn withdraw_and_close_sol(ctx: Context<WithdrawAndCloseSol>) -> Result<()> {
             TimeLockError::InsufficientFunds
         );
         
-        // Transfer withdrawal amount to owner
-        **account_info.try_borrow_mut_lamports()? -= amount_to_transfer;
-        **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount_to_transfer;
-        
-        Ok(())
+        // Transfer withdrawal amount to beneficiary, less the protocol fee
+        transfer_lamports_with_fee(
+            &account_info,
+            &ctx.accounts.beneficiary.to_account_info(),
+            treasury,
+            amount_to_transfer,
+            fee_amount,
+        )
     })();
     
     time_lock_account.end_operation();
     
     match result {
         Ok(_) => {
-            event_msg!("Withdrawal and closure completed: {} lamports + {} rent to {}", 
-                      amount_to_transfer, rent_refund, ctx.accounts.owner.key());
+            event_msg!("Withdrawal and closure completed: {} lamports to {}, {} rent to {}", 
+                      amount_to_transfer - fee_amount, ctx.accounts.beneficiary.key(),
+                      rent_refund, ctx.accounts.owner.key());
             
             emit!(WithdrawalEvent {
                 time_lock_account: ctx.accounts.time_lock_account.key(),
-                owner: ctx.accounts.owner.key(),
-                recipient: ctx.accounts.owner.key(),
-                amount: amount_to_transfer,
+                owner: ctx.accounts.time_lock_account.owner,
+                recipient: ctx.accounts.beneficiary.key(),
+                amount: amount_to_transfer - fee_amount,
+                fee_amount,
+                flat_fee_lamports,
                 remaining_balance: 0,
                 timestamp: Clock::get()?.unix_timestamp,
                 asset_type: AssetType::Sol,
@@ -170,11 +342,79 @@ pub fn withdraw_and_close_sol(ctx: Context<WithdrawAndCloseSol>) -> Result<()> {
             critical_msg!("Withdrawal and closure failed, rolling back: {:?}", e);
             time_lock_account.sol_balance = amount_to_transfer;
             time_lock_account.amount = amount_to_transfer;
-            Err(e.into())
+            Err(e)
         }
     }
 }
 
+// Moves lamports out of the time lock PDA while keeping it rent-exempt
+pub(crate) fn transfer_lamports_from_lock<'info>(
+    time_lock_info: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let rent_exempt_amount = Rent::get()?.minimum_balance(time_lock_info.data_len());
+    let available_lamports = time_lock_info.lamports().saturating_sub(rent_exempt_amount);
+    require!(
+        available_lamports >= amount,
+        TimeLockError::InsufficientFunds
+    );
+    
+    **time_lock_info.try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+    
+    Ok(())
+}
+
+// Splits `amount` lamports out of the lock between `recipient` and the treasury's `fee_amount`
+pub(crate) fn transfer_lamports_with_fee<'info>(
+    time_lock_info: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    treasury: Option<AccountInfo<'info>>,
+    amount: u64,
+    fee_amount: u64,
+) -> Result<()> {
+    if fee_amount > 0 {
+        transfer_lamports_from_lock(time_lock_info, &require_treasury(treasury)?, fee_amount)?;
+    }
+    transfer_lamports_from_lock(time_lock_info, recipient, amount - fee_amount)
+}
+
+// Splits `amount` tokens out of the vault between `destination` and the treasury's `fee_amount`,
+// signed by the lock PDA
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transfer_tokens_with_fee<'info>(
+    token_from_vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: AccountInfo<'info>,
+    treasury_token_account: Option<AccountInfo<'info>>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+    amount: u64,
+    fee_amount: u64,
+) -> Result<()> {
+    if fee_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            from: token_from_vault.to_account_info(),
+            mint: mint.to_account_info(),
+            to: require_treasury(treasury_token_account)?,
+            authority: authority.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer);
+        token_interface::transfer_checked(cpi_ctx, fee_amount, mint.decimals)?;
+    }
+    
+    let cpi_accounts = TransferChecked {
+        from: token_from_vault.to_account_info(),
+        mint: mint.to_account_info(),
+        to: destination,
+        authority,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer);
+    token_interface::transfer_checked(cpi_ctx, amount - fee_amount, mint.decimals)
+}
+
 // ============================================================================
 // TOKEN WITHDRAW
 // ============================================================================
@@ -183,71 +423,1057 @@ pub fn withdraw_and_close_sol(ctx: Context<WithdrawAndCloseSol>) -> Result<()> {
 pub struct WithdrawToken<'info> {
     #[account(
         mut,
-        seeds = [b"time_lock", owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
         close = owner, // Close account and send rent to owner
-        has_one = owner,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        has_one = owner @ TimeLockError::Unauthorized,
         constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
         constraint = time_lock_account.token_vault == token_from_vault.key() @ TimeLockError::InvalidTokenVault
     )]
     pub time_lock_account: Account<'info, TimeLockAccount>,
 
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub beneficiary: Signer<'info>,
 
+    /// CHECK: Rent refund destination, validated by has_one against the stored owner
     #[account(mut)]
-    pub token_from_vault: Account<'info, TokenAccount>,
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_from_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         associated_token::mint = token_from_vault.mint,
-        associated_token::authority = owner
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub token_to_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_from_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
     )]
-    pub token_to_ata: Account<'info, TokenAccount>,
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw_token(ctx: Context<WithdrawToken>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
     let time_lock_account = &ctx.accounts.time_lock_account;
-    let current_timestamp = Clock::get()?.unix_timestamp;
-
-    // Check if unlock time has passed
-    if current_timestamp < time_lock_account.unlock_timestamp {
+    
+    // Check if the unlock condition has been reached
+    if !time_lock_account.is_unlocked()? {
         return err!(TimeLockError::TimeLockNotExpired);
     }
     
     // Validate withdrawal amount
     let amount_to_transfer = time_lock_account.amount;
     require!(amount_to_transfer > 0, TimeLockError::InvalidAmount);
+    let fee_amount = Config::bps_fee(amount_to_transfer, ctx.accounts.config.withdrawal_fee_bps);
     
     // Prepare PDA signing seeds
-    let owner_key = ctx.accounts.owner.key();
-    let unlock_timestamp_le = time_lock_account.unlock_timestamp.to_le_bytes();
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
     let time_lock_seeds = &[
-        b"time_lock",
-        owner_key.as_ref(),
-        unlock_timestamp_le.as_ref(),
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
         &[time_lock_account.bump],
     ];
     let signer = &[&time_lock_seeds[..]];
+    
+    // Execute token transfer, the protocol fee goes to the treasury
+    transfer_tokens_with_fee(
+        &ctx.accounts.token_from_vault,
+        &ctx.accounts.mint,
+        ctx.accounts.token_to_ata.to_account_info(),
+        ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        ctx.accounts.time_lock_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        signer,
+        amount_to_transfer,
+        fee_amount,
+    )?;
+    
+    // Reset amount (account will be closed by Anchor)
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    time_lock_account.amount = 0;
+    
+    msg!("Withdrawn {} tokens from time-locked wallet ({} fee, {} lamports flat fee)", 
+         amount_to_transfer - fee_amount, fee_amount, flat_fee_lamports);
+    Ok(())
+}
 
-    // Setup CPI for token transfer
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.token_from_vault.to_account_info(),
-        to: ctx.accounts.token_to_ata.to_account_info(),
-        authority: ctx.accounts.time_lock_account.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+// ============================================================================
+// TOKEN WITHDRAW AND CLOSE (lock and vault)
+// ============================================================================
 
-    // Execute token transfer
-    anchor_spl::token::transfer(cpi_ctx, amount_to_transfer)?;
+#[derive(Accounts)]
+pub struct WithdrawAndCloseToken<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        has_one = owner @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_from_vault.key() @ TimeLockError::InvalidTokenVault,
+        close = owner // Rent goes back to whoever created and paid for the lock
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
 
-    // Reset amount (account will be closed by Anchor)
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: Rent refund destination for the lock and the vault, validated by has_one against the stored owner
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_from_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_from_vault.mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub token_to_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_from_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_and_close_token(ctx: Context<WithdrawAndCloseToken>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    debug_msg!("Token withdrawal and closure initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
+    
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    if !time_lock_account.is_unlocked()? {
+        return err!(TimeLockError::TimeLockNotExpired);
+    }
+    
+    // Drain whatever the vault holds, including tokens sent to it directly, so it can be closed
+    let amount_to_transfer = ctx.accounts.token_from_vault.amount;
+    let fee_amount = Config::bps_fee(amount_to_transfer, ctx.accounts.config.withdrawal_fee_bps);
+    
+    // Prepare PDA signing seeds
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    if amount_to_transfer > 0 {
+        if let Err(e) = transfer_tokens_with_fee(
+            &ctx.accounts.token_from_vault,
+            &ctx.accounts.mint,
+            ctx.accounts.token_to_ata.to_account_info(),
+            ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+            time_lock_account.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            signer,
+            amount_to_transfer,
+            fee_amount,
+        ) {
+            critical_msg!("Token withdrawal and closure failed: {:?}", e);
+            return Err(e);
+        }
+    }
+    
+    // Close the emptied vault, its rent goes to the owner along with the lock's
+    let vault_rent = ctx.accounts.token_from_vault.to_account_info().lamports();
+    let close_vault_accounts = CloseAccount {
+        account: ctx.accounts.token_from_vault.to_account_info(),
+        destination: ctx.accounts.owner.to_account_info(),
+        authority: time_lock_account.to_account_info(),
+    };
+    let close_vault_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_vault_accounts,
+        signer,
+    );
+    token_interface::close_account(close_vault_ctx)?;
+    
+    let account_rent = Rent::get()?.minimum_balance(time_lock_account.to_account_info().data_len());
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
     let time_lock_account = &mut ctx.accounts.time_lock_account;
     time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(amount_to_transfer)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.end_operation();
+    
+    event_msg!("Token withdrawal and closure completed: {} tokens to {}, {} rent to {}", 
+              amount_to_transfer - fee_amount, ctx.accounts.token_to_ata.key(),
+              account_rent + vault_rent, ctx.accounts.owner.key());
+    
+    emit!(WithdrawalEvent {
+        time_lock_account: time_lock_account.key(),
+        owner: time_lock_account.owner,
+        recipient: ctx.accounts.token_to_ata.key(),
+        amount: amount_to_transfer - fee_amount,
+        fee_amount,
+        flat_fee_lamports,
+        remaining_balance: 0,
+        timestamp: current_timestamp,
+        asset_type: AssetType::Token,
+    });
+    
+    emit!(AccountClosureEvent {
+        time_lock_account: time_lock_account.key(),
+        owner: time_lock_account.owner,
+        rent_refunded: account_rent + vault_rent,
+        timestamp: current_timestamp,
+        closure_reason: format!("Withdrawn and closed, lock rent {} + vault rent {}", account_rent, vault_rent),
+    });
+    
+    // Time lock account will be automatically closed by Anchor
+    Ok(())
+}
+
+// ============================================================================
+// PARTIAL TOKEN WITHDRAW (keeps the lock and vault open)
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawTokenPartial<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_from_vault.key() @ TimeLockError::InvalidTokenVault
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(mut)]
+    pub token_from_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_from_vault.mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub token_to_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_from_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_token_partial(ctx: Context<WithdrawTokenPartial>, amount: u64) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    release_tokens(
+        &mut ctx.accounts.time_lock_account,
+        ctx.accounts.beneficiary.key(),
+        &ctx.accounts.token_from_vault,
+        &ctx.accounts.mint,
+        ctx.accounts.token_to_ata.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        Config::bps_fee(amount, ctx.accounts.config.withdrawal_fee_bps),
+        ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        flat_fee_lamports,
+    )
+}
+
+pub fn withdraw_token_to(ctx: Context<WithdrawTokenTo>, amount: u64) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    release_tokens(
+        &mut ctx.accounts.time_lock_account,
+        ctx.accounts.beneficiary.key(),
+        &ctx.accounts.token_from_vault,
+        &ctx.accounts.mint,
+        ctx.accounts.recipient_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        Config::bps_fee(amount, ctx.accounts.config.withdrawal_fee_bps),
+        ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        flat_fee_lamports,
+    )
+}
+
+// Releases `amount` tokens, less `fee_amount` for the treasury, from the vault to `destination`,
+// keeping the lock and vault open
+#[allow(clippy::too_many_arguments)]
+fn release_tokens<'info>(
+    time_lock_account: &mut Account<'info, TimeLockAccount>,
+    caller: Pubkey,
+    token_from_vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+    fee_amount: u64,
+    treasury_token_account: Option<AccountInfo<'info>>,
+    flat_fee_lamports: u64,
+) -> Result<()> {
+    debug_msg!("Partial token withdrawal initiated for account: {}", time_lock_account.key());
+    
+    time_lock_account.start_operation_with_monitoring(caller)?;
+    time_lock_account.validate_token_withdrawal(amount)?;
+    
+    // Prepare PDA signing seeds
+    let owner_key = time_lock_account.owner;
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    let destination_key = destination.key();
+    let result = transfer_tokens_with_fee(
+        token_from_vault,
+        mint,
+        destination,
+        treasury_token_account,
+        time_lock_account.to_account_info(),
+        token_program,
+        signer,
+        amount,
+        fee_amount,
+    );
+    
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            // Remainder stays locked, account and vault remain open for later draws
+            time_lock_account.amount = time_lock_account.amount
+                .checked_sub(amount)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+                .checked_add(amount)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            
+            event_msg!("Partial token withdrawal completed: {} tokens to {}, {} tokens remain locked", 
+                      amount - fee_amount, destination_key, time_lock_account.amount);
+            
+            emit!(WithdrawalEvent {
+                time_lock_account: time_lock_account.key(),
+                owner: owner_key,
+                recipient: destination_key,
+                amount: amount - fee_amount,
+                fee_amount,
+                flat_fee_lamports,
+                remaining_balance: time_lock_account.amount,
+                timestamp: Clock::get()?.unix_timestamp,
+                asset_type: AssetType::Token,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Partial token withdrawal failed: {:?}", e);
+            Err(e)
+        }
+    }
+}
 
-    msg!("Withdrawn {} tokens from time-locked wallet", amount_to_transfer);
+// ============================================================================
+// WITHDRAW TO RECIPIENT
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawSolTo<'info> {
+    #[account(
+        mut, 
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+    
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    
+    /// CHECK: Any system account chosen by the beneficiary (cold wallet, exchange, multisig vault)
+    #[account(
+        mut,
+        // Paying the lock itself would reduce sol_balance while the lamports stay put
+        constraint = recipient.key() != time_lock_account.key() @ TimeLockError::InvalidRecipient
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Fee destination, only required while the config charges fees
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct WithdrawTokenTo<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_from_vault.key() @ TimeLockError::InvalidTokenVault
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(mut)]
+    pub token_from_vault: InterfaceAccount<'info, TokenAccount>,
+
+    // Any token account of the locked mint, not only the beneficiary's ATA
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == token_from_vault.mint @ TimeLockError::InvalidMint,
+        constraint = recipient_token_account.key() != token_from_vault.key() @ TimeLockError::InvalidRecipient
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_from_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// ============================================================================
+// EARLY WITHDRAW (SOFT LOCKS)
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct EarlyWithdrawSol<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Sol @ TimeLockError::InvalidAssetType
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: Must match the penalty recipient fixed at initialize
+    #[account(
+        mut,
+        constraint = time_lock_account.early_unlock.as_ref().map(|terms| terms.recipient) == Some(penalty_recipient.key())
+            @ TimeLockError::InvalidPenaltyRecipient
+    )]
+    pub penalty_recipient: UncheckedAccount<'info>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Fee destination, only required while the config charges fees
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn early_withdraw_sol(ctx: Context<EarlyWithdrawSol>, amount: u64) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Early SOL withdrawal initiated for account: {}", time_lock_account.key());
+    
+    time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    require!(amount > 0, TimeLockError::InvalidAmount);
+    require!(amount <= time_lock_account.sol_balance, TimeLockError::InsufficientBalance);
+    
+    let (penalty_bps, penalty) = time_lock_account.early_withdrawal_penalty(amount, current_timestamp)?;
+    let payout = amount - penalty;
+    // The withdrawal fee applies to what leaves for the beneficiary, never to the penalty
+    let fee_amount = Config::bps_fee(payout, ctx.accounts.config.withdrawal_fee_bps);
+    
+    debug_msg!("Early withdrawal: {} lamports, penalty {} bps = {} lamports", amount, penalty_bps, penalty);
+    
+    let previous_sol_balance = time_lock_account.sol_balance;
+    let previous_amount = time_lock_account.amount;
+    let previous_withdrawn = time_lock_account.withdrawn_amount;
+    
+    // Update balances before transfer, the remainder stays locked
+    time_lock_account.sol_balance = previous_sol_balance
+        .checked_sub(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.amount = previous_amount
+        .checked_sub(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.withdrawn_amount = previous_withdrawn
+        .checked_add(amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    let remaining_balance = time_lock_account.sol_balance;
+    
+    let time_lock_info = time_lock_account.to_account_info();
+    let treasury = ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info());
+    let result = transfer_lamports_with_fee(&time_lock_info, &ctx.accounts.beneficiary.to_account_info(), treasury, payout, fee_amount)
+        .and_then(|_| transfer_lamports_from_lock(&time_lock_info, &ctx.accounts.penalty_recipient.to_account_info(), penalty));
+    
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            event_msg!("Early withdrawal completed: {} lamports to {}, {} lamports penalty, {} lamports remain locked",
+                      payout - fee_amount, ctx.accounts.beneficiary.key(), penalty, remaining_balance);
+            
+            emit!(EarlyWithdrawalEvent {
+                time_lock_account: time_lock_account.key(),
+                beneficiary: ctx.accounts.beneficiary.key(),
+                recipient: ctx.accounts.beneficiary.key(),
+                amount,
+                penalty,
+                penalty_bps,
+                penalty_recipient: ctx.accounts.penalty_recipient.key(),
+                fee_amount,
+                flat_fee_lamports,
+                remaining_balance,
+                timestamp: current_timestamp,
+                asset_type: AssetType::Sol,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Early withdrawal failed, rolling back: {:?}", e);
+            time_lock_account.sol_balance = previous_sol_balance;
+            time_lock_account.amount = previous_amount;
+            time_lock_account.withdrawn_amount = previous_withdrawn;
+            Err(e)
+        }
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct EarlyWithdrawToken<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Token @ TimeLockError::InvalidAssetType,
+        constraint = time_lock_account.token_vault == token_from_vault.key() @ TimeLockError::InvalidTokenVault
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    #[account(mut)]
+    pub token_from_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_from_vault.mint,
+        associated_token::authority = beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub token_to_ata: InterfaceAccount<'info, TokenAccount>,
+
+    // Any token account of the locked mint held by the configured penalty recipient
+    #[account(
+        mut,
+        constraint = penalty_token_account.mint == token_from_vault.mint @ TimeLockError::InvalidMint,
+        constraint = time_lock_account.early_unlock.as_ref().map(|terms| terms.recipient) == Some(penalty_token_account.owner)
+            @ TimeLockError::InvalidPenaltyRecipient
+    )]
+    pub penalty_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_from_vault.mint @ TimeLockError::InvalidMint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Flat fee destination, only required while the config charges a flat fee
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    // Treasury's account for this mint, only required while a bps fee applies
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn early_withdraw_token(ctx: Context<EarlyWithdrawToken>, amount: u64) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Early token withdrawal initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
+    require!(ctx.accounts.time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    require!(amount > 0, TimeLockError::InvalidAmount);
+    require!(amount <= ctx.accounts.time_lock_account.amount, TimeLockError::InsufficientBalance);
+    
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    let (penalty_bps, penalty) = time_lock_account.early_withdrawal_penalty(amount, current_timestamp)?;
+    let payout = amount - penalty;
+    // The withdrawal fee applies to what leaves for the beneficiary, never to the penalty
+    let fee_amount = Config::bps_fee(payout, ctx.accounts.config.withdrawal_fee_bps);
+    
+    // Prepare PDA signing seeds
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let mut result = transfer_tokens_with_fee(
+        &ctx.accounts.token_from_vault,
+        &ctx.accounts.mint,
+        ctx.accounts.token_to_ata.to_account_info(),
+        ctx.accounts.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+        time_lock_account.to_account_info(),
+        token_program.clone(),
+        signer,
+        payout,
+        fee_amount,
+    );
+    
+    if result.is_ok() && penalty > 0 {
+        let penalty_accounts = TransferChecked {
+            from: ctx.accounts.token_from_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.penalty_token_account.to_account_info(),
+            authority: time_lock_account.to_account_info(),
+        };
+        result = token_interface::transfer_checked(
+            CpiContext::new_with_signer(token_program, penalty_accounts, signer),
+            penalty,
+            ctx.accounts.mint.decimals,
+        );
+    }
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    time_lock_account.end_operation();
+    
+    match result {
+        Ok(_) => {
+            time_lock_account.amount = time_lock_account.amount
+                .checked_sub(amount)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+                .checked_add(amount)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            
+            event_msg!("Early token withdrawal completed: {} tokens to {}, {} tokens penalty, {} tokens remain locked",
+                      payout - fee_amount, ctx.accounts.beneficiary.key(), penalty, time_lock_account.amount);
+            
+            emit!(EarlyWithdrawalEvent {
+                time_lock_account: time_lock_account.key(),
+                beneficiary: ctx.accounts.beneficiary.key(),
+                recipient: ctx.accounts.token_to_ata.key(),
+                amount,
+                penalty,
+                penalty_bps,
+                penalty_recipient: ctx.accounts.penalty_token_account.key(),
+                fee_amount,
+                flat_fee_lamports,
+                remaining_balance: time_lock_account.amount,
+                timestamp: current_timestamp,
+                asset_type: AssetType::Token,
+            });
+            
+            Ok(())
+        },
+        Err(e) => {
+            critical_msg!("Early token withdrawal failed: {:?}", e);
+            Err(e)
+        }
+    }
+}
+
+// ============================================================================
+// BASKET WITHDRAW
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct WithdrawBasket<'info> {
+    #[account(
+        mut,
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
+        bump = time_lock_account.bump,
+        has_one = beneficiary @ TimeLockError::Unauthorized,
+        has_one = owner @ TimeLockError::Unauthorized,
+        constraint = time_lock_account.asset_type == AssetType::Basket @ TimeLockError::InvalidAssetType,
+        close = owner // Rent goes back to whoever created and paid for the lock
+    )]
+    pub time_lock_account: Account<'info, TimeLockAccount>,
+
+    // Every basket asset follows in remaining_accounts as a (vault, mint, beneficiary token account)
+    // triple in basket order, extended by the treasury's token account for that mint while a
+    // withdrawal fee applies; multisig co-signers and, for baskets mixing legacy and Token-2022
+    // mints, the second token program come after the legs
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: Validated against time_lock_account.owner, receives lock and vault rent
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    // Program-wide policy (fees)
+    #[account(seeds = [Config::SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Fee destination, only required while the config charges fees
+    #[account(mut, address = config.treasury @ TimeLockError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_basket<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawBasket<'info>>) -> Result<()> {
+    ctx.accounts.time_lock_account.require_withdrawal_approval(&ctx.accounts.beneficiary.key(), ctx.remaining_accounts)?;
+    
+    // 💸 Flat protocol fee, paid in lamports by the caller once for the whole basket
+    let flat_fee_lamports = ctx.accounts.config.flat_fee_lamports;
+    transfer_fee_lamports(
+        ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        ctx.accounts.system_program.to_account_info(),
+        flat_fee_lamports,
+    )?;
+    let fee_bps = ctx.accounts.config.withdrawal_fee_bps;
+    let treasury_key = ctx.accounts.config.treasury;
+    
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    
+    debug_msg!("Basket withdrawal initiated for account: {}", ctx.accounts.time_lock_account.key());
+    
+    ctx.accounts.time_lock_account.start_operation_with_monitoring(ctx.accounts.beneficiary.key())?;
+    
+    // A basket may hold only tokens, so an empty SOL balance is fine here
+    let time_lock_account = &ctx.accounts.time_lock_account;
+    require!(time_lock_account.is_initialized, TimeLockError::NotInitialized);
+    require!(time_lock_account.is_unlocked()?, TimeLockError::TimeLockNotExpired);
+    
+    let basket_assets = time_lock_account.basket_assets.clone();
+    let leg_len = if fee_bps > 0 { 4 } else { 3 };
+    require!(
+        ctx.remaining_accounts.len() >= basket_assets.len() * leg_len,
+        TimeLockError::InvalidTokenVault
+    );
+    
+    // Prepare PDA signing seeds
+    let owner_key = time_lock_account.owner;
+    let original_owner = time_lock_account.original_owner;
+    let seed_id = time_lock_account.seed_id();
+    let time_lock_seeds = &[
+        time_lock_account.seed_prefix(),
+        original_owner.as_ref(),
+        seed_id.as_ref(),
+        &[time_lock_account.bump],
+    ];
+    let signer = &[&time_lock_seeds[..]];
+    
+    // Every mint leg: drain the vault to the beneficiary, then close it
+    let mut vault_rent: u64 = 0;
+    let mut withdrawn: u64 = 0;
+    for (asset, accounts) in basket_assets.iter().zip(ctx.remaining_accounts.chunks(leg_len)) {
+        let (vault_info, mint_info, destination_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        require!(vault_info.key() == asset.vault, TimeLockError::InvalidTokenVault);
+        require!(mint_info.key() == asset.mint, TimeLockError::InvalidMint);
+        
+        let vault = InterfaceAccount::<TokenAccount>::try_from(vault_info)?;
+        let mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        let destination = InterfaceAccount::<TokenAccount>::try_from(destination_info)?;
+        require!(destination.mint == asset.mint, TimeLockError::InvalidMint);
+        require!(destination.owner == ctx.accounts.beneficiary.key(), TimeLockError::Unauthorized);
+        
+        // Each leg is driven by the token program that owns its vault
+        let token_program = if *vault_info.owner == ctx.accounts.token_program.key() {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.remaining_accounts
+                .iter()
+                .find(|account| account.key == vault_info.owner)
+                .cloned()
+                .ok_or(TimeLockError::InvalidTokenVault)?
+        };
+        
+        // The treasury's account for this mint rides along as the fourth account of the leg
+        let treasury_token_account = match accounts.get(3) {
+            Some(treasury_info) if leg_len == 4 => {
+                let treasury_token_account = InterfaceAccount::<TokenAccount>::try_from(treasury_info)?;
+                require!(treasury_token_account.mint == asset.mint, TimeLockError::InvalidMint);
+                require!(treasury_token_account.owner == treasury_key, TimeLockError::InvalidTreasury);
+                Some(treasury_info.clone())
+            },
+            _ => None,
+        };
+        
+        // Move the vault's actual balance so it can be closed, even if tokens were sent directly
+        let fee_amount = Config::bps_fee(vault.amount, fee_bps);
+        if vault.amount > 0 {
+            transfer_tokens_with_fee(
+                &vault,
+                &mint,
+                destination_info.clone(),
+                treasury_token_account,
+                time_lock_account.to_account_info(),
+                token_program.clone(),
+                signer,
+                vault.amount,
+                fee_amount,
+            )?;
+        }
+        
+        // Count what actually left the vault, direct transfers included
+        withdrawn = withdrawn
+            .checked_add(vault.amount)
+            .ok_or(TimeLockError::ArithmeticOverflow)?;
+        vault_rent = vault_rent
+            .checked_add(vault_info.lamports())
+            .ok_or(TimeLockError::ArithmeticOverflow)?;
+        let close_vault_accounts = CloseAccount {
+            account: vault_info.clone(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: time_lock_account.to_account_info(),
+        };
+        let close_vault_ctx = CpiContext::new_with_signer(token_program, close_vault_accounts, signer);
+        token_interface::close_account(close_vault_ctx)?;
+        
+        event_msg!("Basket leg released: {} tokens of mint {}", vault.amount - fee_amount, asset.mint);
+        
+        emit!(WithdrawalEvent {
+            time_lock_account: time_lock_account.key(),
+            owner: owner_key,
+            recipient: destination_info.key(),
+            amount: vault.amount - fee_amount,
+            fee_amount,
+            flat_fee_lamports: 0,
+            remaining_balance: 0,
+            timestamp: current_timestamp,
+            asset_type: AssetType::Token,
+        });
+    }
+    
+    // Native SOL leg, the rent-exempt reserve goes to the owner when Anchor closes the account
+    let sol_amount = time_lock_account.sol_balance;
+    let sol_fee_amount = Config::bps_fee(sol_amount, fee_bps);
+    transfer_lamports_with_fee(
+        &time_lock_account.to_account_info(),
+        &ctx.accounts.beneficiary.to_account_info(),
+        ctx.accounts.treasury.as_ref().map(|treasury| treasury.to_account_info()),
+        sol_amount,
+        sol_fee_amount,
+    )?;
+    
+    emit!(WithdrawalEvent {
+        time_lock_account: time_lock_account.key(),
+        owner: owner_key,
+        recipient: ctx.accounts.beneficiary.key(),
+        amount: sol_amount - sol_fee_amount,
+        fee_amount: sol_fee_amount,
+        flat_fee_lamports,
+        remaining_balance: 0,
+        timestamp: current_timestamp,
+        asset_type: AssetType::Sol,
+    });
+    
+    let account_rent = Rent::get()?.minimum_balance(time_lock_account.to_account_info().data_len());
+    
+    let time_lock_account = &mut ctx.accounts.time_lock_account;
+    let withdrawn = withdrawn
+        .checked_add(sol_amount)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.sol_balance = 0;
+    time_lock_account.amount = 0;
+    time_lock_account.withdrawn_amount = time_lock_account.withdrawn_amount
+        .checked_add(withdrawn)
+        .ok_or(TimeLockError::ArithmeticOverflow)?;
+    time_lock_account.basket_assets.clear();
+    time_lock_account.end_operation();
+    
+    event_msg!("Basket withdrawal completed: {} lamports and {} mints to {}",
+              sol_amount, basket_assets.len(), ctx.accounts.beneficiary.key());
+    
+    emit!(AccountClosureEvent {
+        time_lock_account: time_lock_account.key(),
+        owner: owner_key,
+        rent_refunded: account_rent + vault_rent,
+        timestamp: current_timestamp,
+        closure_reason: format!("Basket withdrawal of SOL and {} mints", basket_assets.len()),
+    });
+    
+    // Time lock account will be automatically closed by Anchor
     Ok(())
-}
\ No newline at end of file
+}
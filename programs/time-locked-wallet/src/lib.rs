@@ -7,7 +7,7 @@ pub mod events;
 pub mod utils;
 
 use instructions::*;
-use state::AssetType;
+use state::{AssetType, ConfigParams, ReleaseSchedule, UnlockTranche, MultisigConfig, GuardianSet, PendingRecovery, EarlyUnlockPenalty, UnlockCondition, HashLock, Heir, Inheritance, BasketAsset};
 
 declare_id!("899SKikn1WiRBSurKhMZyNCNvYmWXVE6hZFYbFim293g");
 
@@ -15,44 +15,332 @@ declare_id!("899SKikn1WiRBSurKhMZyNCNvYmWXVE6hZFYbFim293g");
 pub mod time_locked_wallet {
     use super::*;
 
+    // Creates the program-wide Config singleton, callable once by the upgrade authority
+    // which becomes the admin; every lock instruction reads its policy from it
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        instructions::initialize_config(ctx, params)
+    }
+
+    // Replaces the policy values and optionally hands the admin role to a new key
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams, new_admin: Option<Pubkey>) -> Result<()> {
+        instructions::update_config(ctx, params, new_admin)
+    }
+
+    // Emergency switch blocking every initialize flavour and deposit while set
+    // Withdrawals, claims and closures keep working so users are never trapped;
+    // the admin may be a multisig address
+    pub fn set_paused(ctx: Context<UpdateConfig>, paused: bool) -> Result<()> {
+        instructions::set_paused(ctx, paused)
+    }
+
     // Initializes a new time-locked wallet account
-    // The account is a PDA derived from the initializer's address and unlock_timestamp
-    // It can be used to hold either SOL or SPL tokens with time-locked access
+    // The account is a PDA derived from the initializer's address and the initial unlock_timestamp
+    // It can be used to hold either SOL or SPL tokens with time-locked access; token locks pass their mint here
     pub fn initialize(ctx: Context<Initialize>, unlock_timestamp: i64, asset_type: AssetType) -> Result<()> {
         instructions::initialize(ctx, unlock_timestamp, asset_type)
     }
 
+    // Initializes a new time-locked wallet account addressed by a caller-chosen lock_id
+    // The PDA is derived from the initializer's address and lock_id, so several locks
+    // may share an unlock_timestamp; accounts created by `initialize` keep their old seeds
+    pub fn initialize_with_id(
+        ctx: Context<InitializeWithId>,
+        lock_id: u64,
+        unlock_timestamp: i64,
+        asset_type: AssetType,
+    ) -> Result<()> {
+        instructions::initialize_with_id(ctx, lock_id, unlock_timestamp, asset_type)
+    }
+
+    // Initializes an id-addressed wallet that unlocks at a slot or epoch instead of a timestamp
+    // Avoids depending on validator-reported wall-clock time, e.g. to line up with staking epochs
+    pub fn initialize_with_condition(
+        ctx: Context<InitializeWithCondition>,
+        lock_id: u64,
+        unlock_condition: UnlockCondition,
+        asset_type: AssetType,
+    ) -> Result<()> {
+        instructions::initialize_with_condition(ctx, lock_id, unlock_condition, asset_type)
+    }
+
+    // Initializes an id-addressed hash-time-lock (HTLC) for atomic swaps
+    // The counterparty is paid on the sha256 preimage of `hash` before `deadline`,
+    // afterwards the initializer reclaims through the regular withdrawals
+    pub fn initialize_htlc(
+        ctx: Context<InitializeHtlc>,
+        lock_id: u64,
+        hash: [u8; 32],
+        deadline: i64,
+        asset_type: AssetType,
+    ) -> Result<()> {
+        instructions::initialize_htlc(ctx, lock_id, hash, deadline, asset_type)
+    }
+
+    // Initializes an id-addressed dead-man's-switch lock
+    // The owner may withdraw at any time but must call heartbeat every heartbeat_interval seconds,
+    // otherwise the heirs can split the funds by basis points
+    pub fn initialize_inheritance(
+        ctx: Context<InitializeInheritance>,
+        lock_id: u64,
+        heartbeat_interval: i64,
+        heirs: Vec<Heir>,
+        asset_type: AssetType,
+    ) -> Result<()> {
+        instructions::initialize_inheritance(ctx, lock_id, heartbeat_interval, heirs, asset_type)
+    }
+
+    // Initializes a vesting time-locked wallet
+    // Funds release linearly from start_ts to end_ts, with nothing claimable before cliff_ts
+    // The PDA is derived from the initializer's address and end_ts
+    // A revocable grant lets the owner claw back the unvested remainder later
+    pub fn initialize_vesting(
+        ctx: Context<InitializeVesting>,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+        asset_type: AssetType,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::initialize_vesting(ctx, start_ts, cliff_ts, end_ts, asset_type, revocable)
+    }
+
+    // Same as initialize_vesting, but the PDA is derived from a caller-chosen lock_id
+    // so several grants may end at the same second
+    pub fn initialize_vesting_with_id(
+        ctx: Context<InitializeVestingWithId>,
+        lock_id: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+        asset_type: AssetType,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::initialize_vesting_with_id(ctx, lock_id, start_ts, cliff_ts, end_ts, asset_type, revocable)
+    }
+
+    // Initializes a time-locked wallet that releases funds in discrete tranches
+    // Each (timestamp, amount) tranche becomes claimable once its timestamp passes
+    // The PDA is derived from the initializer's address and the final tranche timestamp
+    pub fn initialize_tranches(
+        ctx: Context<InitializeTranches>,
+        tranches: Vec<UnlockTranche>,
+        asset_type: AssetType,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::initialize_tranches(ctx, tranches, asset_type, revocable)
+    }
+
+    // Same as initialize_tranches, but the PDA is derived from a caller-chosen lock_id
+    pub fn initialize_tranches_with_id(
+        ctx: Context<InitializeTranchesWithId>,
+        lock_id: u64,
+        tranches: Vec<UnlockTranche>,
+        asset_type: AssetType,
+        revocable: bool,
+    ) -> Result<()> {
+        instructions::initialize_tranches_with_id(ctx, lock_id, tranches, asset_type, revocable)
+    }
+
+    // Initializes an id-addressed soft lock that can be left early at a penalty
+    // The penalty in basis points may decay linearly to zero at unlock_timestamp
+    // and is paid to the penalty_recipient account fixed here
+    pub fn initialize_soft_lock(
+        ctx: Context<InitializeSoftLock>,
+        lock_id: u64,
+        unlock_timestamp: i64,
+        asset_type: AssetType,
+        penalty_bps: u16,
+        linear_decay: bool,
+    ) -> Result<()> {
+        instructions::initialize_soft_lock(ctx, lock_id, unlock_timestamp, asset_type, penalty_bps, linear_decay)
+    }
+
     // Locks an amount of SOL into the time-locked wallet
     // SOL is transferred from the depositor to the program's PDA account
+    // Deposits are permissionless, anyone can fund someone else's lock
+    // The configured deposit fee is deducted and sent to the treasury, the flat fee is charged on top
     pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
         instructions::deposit_sol(ctx, amount)
     }
 
     // Locks an amount of SPL tokens into the time-locked wallet
     // Tokens are transferred from the depositor's ATA to the program's token vault
+    // Deposits are permissionless, anyone can fund someone else's lock
+    // The configured deposit fee is taken in tokens, the flat fee in lamports from the depositor
     pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
         instructions::deposit_token(ctx, amount)
     }
 
+    // Adds SPL tokens of any mint to a basket lock, creating that mint's vault on first use
+    // Deposits are permissionless, anyone can fund someone else's lock
+    pub fn deposit_basket_token(ctx: Context<DepositBasketToken>, amount: u64) -> Result<()> {
+        instructions::deposit_basket_token(ctx, amount)
+    }
+
     // Withdraws SOL from the time-locked wallet
     // This instruction is only successful if the unlock_timestamp has passed
     pub fn withdraw_sol(ctx: Context<WithdrawSol>) -> Result<()> {
         instructions::withdraw_sol(ctx)
     }
 
-    // Withdraws SOL and closes the account, refunding rent to owner
+    // Withdraws part of the SOL balance, leaving the remainder locked
+    // This instruction is only successful if the unlock_timestamp has passed
+    pub fn withdraw_sol_partial(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
+        instructions::withdraw_sol_partial(ctx, amount)
+    }
+
+    // Withdraws part of the SOL balance to any recipient chosen by the beneficiary
+    // This instruction is only successful if the unlock_timestamp has passed
+    pub fn withdraw_sol_to(ctx: Context<WithdrawSolTo>, amount: u64) -> Result<()> {
+        instructions::withdraw_sol_to(ctx, amount)
+    }
+
+    // Withdraws SOL to the beneficiary and closes the account, refunding rent to owner
     pub fn withdraw_and_close_sol(ctx: Context<WithdrawAndCloseSol>) -> Result<()> {
         instructions::withdraw_and_close_sol(ctx)
     }
 
+    // Releases every asset of an unlocked basket to the beneficiary in one transaction
+    // and closes the lock and all of its vaults, refunding rent to owner
+    pub fn withdraw_basket<'info>(ctx: Context<'_, '_, 'info, 'info, WithdrawBasket<'info>>) -> Result<()> {
+        instructions::withdraw_basket(ctx)
+    }
+
     // Withdraws SPL tokens from the time-locked wallet
     // This instruction is only successful if the unlock_timestamp has passed
     pub fn withdraw_token(ctx: Context<WithdrawToken>) -> Result<()> {
         instructions::withdraw_token(ctx)
     }
 
+    // Withdraws SPL tokens to the beneficiary and closes both the vault and the account,
+    // refunding the rent of each to owner
+    pub fn withdraw_and_close_token(ctx: Context<WithdrawAndCloseToken>) -> Result<()> {
+        instructions::withdraw_and_close_token(ctx)
+    }
+
+    // Withdraws part of the locked SPL tokens
+    // The time lock account and its token vault stay open for later draws and deposits
+    pub fn withdraw_token_partial(ctx: Context<WithdrawTokenPartial>, amount: u64) -> Result<()> {
+        instructions::withdraw_token_partial(ctx, amount)
+    }
+
+    // Withdraws part of the locked SPL tokens to any token account of the locked mint
+    // This instruction is only successful if the unlock_timestamp has passed
+    pub fn withdraw_token_to(ctx: Context<WithdrawTokenTo>, amount: u64) -> Result<()> {
+        instructions::withdraw_token_to(ctx, amount)
+    }
+
+    // Withdraws SOL from a soft lock before unlock_timestamp
+    // The current penalty and the protocol fee are deducted and paid out separately
+    pub fn early_withdraw_sol(ctx: Context<EarlyWithdrawSol>, amount: u64) -> Result<()> {
+        instructions::early_withdraw_sol(ctx, amount)
+    }
+
+    // Withdraws SPL tokens from a soft lock before unlock_timestamp
+    // The current penalty and the protocol fee are deducted into their own token accounts
+    pub fn early_withdraw_token(ctx: Context<EarlyWithdrawToken>, amount: u64) -> Result<()> {
+        instructions::early_withdraw_token(ctx, amount)
+    }
+
+    // Claims the vested or matured portion of a SOL lock that has not been withdrawn yet
+    pub fn claim_vested_sol(ctx: Context<WithdrawSol>) -> Result<()> {
+        instructions::claim_vested_sol(ctx)
+    }
+
+    // Claims the vested or matured portion of a token lock that has not been withdrawn yet
+    pub fn claim_vested_token(ctx: Context<WithdrawTokenPartial>) -> Result<()> {
+        instructions::claim_vested_token(ctx)
+    }
+
+    // Pays the whole SOL balance of a hashlock to its counterparty on a matching preimage
+    // Callable by anyone before the deadline, the preimage is published in the event
+    pub fn claim_with_preimage_sol(ctx: Context<ClaimWithPreimageSol>, preimage: Vec<u8>) -> Result<()> {
+        instructions::claim_with_preimage_sol(ctx, preimage)
+    }
+
+    // Pays the whole token balance of a hashlock to the counterparty's token account
+    pub fn claim_with_preimage_token(ctx: Context<ClaimWithPreimageToken>, preimage: Vec<u8>) -> Result<()> {
+        instructions::claim_with_preimage_token(ctx, preimage)
+    }
+
+    // Rewrites a lock created before the layout grew, permissionless since every new field
+    // gets a fixed default (seed_timestamp = unlock_timestamp, original_owner = beneficiary = owner)
+    pub fn migrate_legacy_account(ctx: Context<MigrateLegacyAccount>) -> Result<()> {
+        instructions::migrate_legacy_account(ctx)
+    }
+
+    // Pushes the unlock timestamp of a single-cliff lock further into the future
+    // Funds never leave the PDA, whose address stays derived from the original timestamp
+    pub fn extend_lock(ctx: Context<ExtendLock>, new_unlock_timestamp: i64) -> Result<()> {
+        instructions::extend_lock(ctx, new_unlock_timestamp)
+    }
+
+    // Proposes a new owner, the first step of a two-step ownership transfer
+    pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Pubkey) -> Result<()> {
+        instructions::propose_owner(ctx, new_owner)
+    }
+
+    // Accepts a pending ownership transfer, signed by the proposed owner
+    // On self-locks the withdrawal rights move to the new owner as well
+    pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+        instructions::accept_ownership(ctx)
+    }
+
+    // Moves the withdrawal rights to a new key, signed by both the current and the new beneficiary
+    // This is the rotation path for grant beneficiaries, whose rights never follow the owner
+    pub fn rotate_beneficiary(ctx: Context<RotateBeneficiary>) -> Result<()> {
+        instructions::rotate_beneficiary(ctx)
+    }
+
+    // Configures an M-of-N signer set for withdrawals, closures and ownership changes
+    // Owner and beneficiary both sign; withdrawals are only gated on locks the owner holds for themselves
+    // Co-signers are passed as signing remaining accounts; an empty list removes the requirement
+    pub fn set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::set_multisig(ctx, signers, threshold)
+    }
+
+    // Configures guardians that can jointly recover the lock if the owner key is lost
+    // An empty list removes them and drops any recovery in progress
+    pub fn set_guardians(ctx: Context<SetGuardians>, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::set_guardians(ctx, guardians, threshold)
+    }
+
+    // Starts a guardian recovery that rotates the owner after a 7 day delay
+    // Co-guardians are passed as signing remaining accounts
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>, new_owner: Pubkey) -> Result<()> {
+        instructions::initiate_recovery(ctx, new_owner)
+    }
+
+    // Cancels a pending recovery, signed by the current owner
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        instructions::cancel_recovery(ctx)
+    }
+
+    // Completes a pending recovery once its delay has passed, callable by anyone
+    pub fn complete_recovery(ctx: Context<CompleteRecovery>) -> Result<()> {
+        instructions::complete_recovery(ctx)
+    }
+
+    // Records that the owner is still active, pushing back the heirs' claim deadline
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat(ctx)
+    }
+
+    // Splits a lapsed inheritance lock's SOL between the heirs and closes it, rent returns to the original owner
+    // Heir wallets are passed as writable remaining accounts in listed order
+    pub fn claim_inheritance_sol<'info>(ctx: Context<'_, '_, '_, 'info, ClaimInheritanceSol<'info>>) -> Result<()> {
+        instructions::claim_inheritance_sol(ctx)
+    }
+
+    // Splits a lapsed inheritance lock's tokens between the heirs, closing the lock and its vault
+    // Rent of both returns to the original owner; heir token accounts are passed as writable remaining accounts in listed order
+    pub fn claim_inheritance_token<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimInheritanceToken<'info>>) -> Result<()> {
+        instructions::claim_inheritance_token(ctx)
+    }
+
     // Closes an empty time-locked account and refunds rent to owner
-    pub fn close_empty_account(ctx: Context<CloseEmptyAccount>) -> Result<()> {
+    // Basket locks also close their per-mint vaults, sweeping stray tokens to the beneficiary
+    pub fn close_empty_account<'info>(ctx: Context<'_, '_, 'info, 'info, CloseEmptyAccount<'info>>) -> Result<()> {
         instructions::close_empty_account(ctx)
     }
 
@@ -61,29 +349,80 @@ pub mod time_locked_wallet {
         instructions::close_token_account(ctx)
     }
 
+    // Revokes a revocable SOL grant and closes it
+    // The vested portion goes to the beneficiary, the unvested remainder and rent to the owner
+    pub fn revoke_sol_grant(ctx: Context<RevokeSolGrant>) -> Result<()> {
+        instructions::revoke_sol_grant(ctx)
+    }
+
+    // Revokes a revocable token grant, closing both the lock and its vault
+    // The vested portion goes to the beneficiary, the unvested remainder and rent to the owner
+    pub fn revoke_token_grant(ctx: Context<RevokeTokenGrant>) -> Result<()> {
+        instructions::revoke_token_grant(ctx)
+    }
+
     // Force closes an expired account (cleanup mechanism)
-    // Can be called by anyone after 1 year past expiration
+    // Can be called by anyone once the configured grace period has passed; the principal goes to the beneficiary,
+    // the rent to the current owner and the caller receives a bounded bounty
+    // The original owner is paid only while it is still the owner or beneficiary, see the routing rule in close.rs
     pub fn force_close_expired(ctx: Context<ForceCloseExpired>) -> Result<()> {
         instructions::force_close_expired(ctx)
     }
 
+    // Force closes an expired token lock and its vault
+    // Tokens go to the beneficiary's ATA, vault and lock rent to the current owner minus the bounty
+    pub fn force_close_expired_token(ctx: Context<ForceCloseExpiredToken>) -> Result<()> {
+        instructions::force_close_expired_token(ctx)
+    }
+
+    // Force closes an expired basket lock and all of its vaults
+    // SOL and tokens go to the beneficiary, lock and vault rent to the current owner minus the bounty
+    pub fn force_close_expired_basket<'info>(ctx: Context<'_, '_, 'info, 'info, ForceCloseExpiredBasket<'info>>) -> Result<()> {
+        instructions::force_close_expired_basket(ctx)
+    }
+
     // View function to get wallet info without modifying state
     pub fn get_wallet_info(ctx: Context<GetWalletInfo>) -> Result<WalletInfo> {
         let time_lock_account = &ctx.accounts.time_lock_account;
-        let current_timestamp = Clock::get()?.unix_timestamp;
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+        let is_timestamp_lock = time_lock_account.unlock_condition == UnlockCondition::Timestamp;
+        let next_release = time_lock_account.next_release(current_timestamp);
         
         Ok(WalletInfo {
             owner: time_lock_account.owner,
+            beneficiary: time_lock_account.beneficiary,
+            original_owner: time_lock_account.original_owner,
+            pending_owner: time_lock_account.pending_owner,
+            lock_id: time_lock_account.lock_id,
             unlock_timestamp: time_lock_account.unlock_timestamp,
             asset_type: time_lock_account.asset_type.clone(),
             amount: time_lock_account.amount,
             token_vault: time_lock_account.token_vault,
-            is_unlocked: current_timestamp >= time_lock_account.unlock_timestamp,
-            time_remaining: if current_timestamp < time_lock_account.unlock_timestamp {
+            mint: time_lock_account.mint,
+            unlock_condition: time_lock_account.unlock_condition,
+            is_unlocked: time_lock_account.unlock_condition.is_met(&clock, time_lock_account.unlock_timestamp),
+            time_remaining: if is_timestamp_lock && current_timestamp < time_lock_account.unlock_timestamp {
                 time_lock_account.unlock_timestamp - current_timestamp
             } else {
                 0
             },
+            schedule: time_lock_account.schedule.clone(),
+            withdrawn_amount: time_lock_account.withdrawn_amount,
+            claimable_amount: time_lock_account.claimable_amount(current_timestamp)?,
+            revocable: time_lock_account.revocable,
+            multisig: time_lock_account.multisig.clone(),
+            guardians: time_lock_account.guardians.clone(),
+            pending_recovery: time_lock_account.pending_recovery.clone(),
+            early_unlock: time_lock_account.early_unlock.clone(),
+            hashlock: time_lock_account.hashlock.clone(),
+            inheritance: time_lock_account.inheritance.clone(),
+            basket_assets: time_lock_account.basket_assets.clone(),
+            current_penalty_bps: time_lock_account.early_unlock
+                .as_ref()
+                .map_or(0, |terms| terms.penalty_bps_at(current_timestamp, time_lock_account.unlock_timestamp)),
+            next_release_timestamp: next_release.map(|(timestamp, _)| timestamp),
+            next_release_amount: next_release.map(|(_, amount)| amount).unwrap_or(0),
         })
     }
 }
@@ -91,7 +430,7 @@ pub mod time_locked_wallet {
 #[derive(Accounts)]
 pub struct GetWalletInfo<'info> {
     #[account(
-        seeds = [b"time_lock", owner.key().as_ref(), &time_lock_account.unlock_timestamp.to_le_bytes()],
+        seeds = [time_lock_account.seed_prefix(), time_lock_account.original_owner.as_ref(), &time_lock_account.seed_id()],
         bump = time_lock_account.bump,
         has_one = owner,
     )]
@@ -102,10 +441,30 @@ pub struct GetWalletInfo<'info> {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct WalletInfo {
     pub owner: Pubkey,
+    pub beneficiary: Pubkey,
+    pub original_owner: Pubkey,
+    pub pending_owner: Option<Pubkey>,
+    pub lock_id: Option<u64>,
     pub unlock_timestamp: i64,
+    pub unlock_condition: UnlockCondition,
     pub asset_type: AssetType,
     pub amount: u64,
     pub token_vault: Pubkey,
+    pub mint: Pubkey,
     pub is_unlocked: bool,
     pub time_remaining: i64,
+    pub schedule: ReleaseSchedule,
+    pub withdrawn_amount: u64,
+    pub claimable_amount: u64,
+    pub revocable: bool,
+    pub next_release_timestamp: Option<i64>,
+    pub next_release_amount: u64,
+    pub multisig: Option<MultisigConfig>,
+    pub guardians: Option<GuardianSet>,
+    pub pending_recovery: Option<PendingRecovery>,
+    pub early_unlock: Option<EarlyUnlockPenalty>,
+    pub current_penalty_bps: u16,
+    pub hashlock: Option<HashLock>,
+    pub inheritance: Option<Inheritance>,
+    pub basket_assets: Vec<BasketAsset>, // per-mint balances of a basket, `amount` holds its SOL
 }
\ No newline at end of file
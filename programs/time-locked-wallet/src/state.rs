@@ -10,7 +10,7 @@ use crate::errors::TimeLockError;
 // This account is a PDA (Program Derived Address)
 #[account]
 pub struct TimeLockAccount {
-    pub owner: Pubkey, // original owner, can withdraw funds.
+    pub owner: Pubkey, // creator and funder of the lock, receives rent refunds
     pub unlock_timestamp: i64, // timestamp when funds can be withdrawn.
     pub asset_type: AssetType, // SOL or SPL token
     pub bump: u8, // bump seed for PDA
@@ -20,6 +20,23 @@ pub struct TimeLockAccount {
     pub sol_balance: u64, // balance of SOL locked
     pub spl_token_account: Option<Pubkey>,
     pub is_processing: bool, // flag to prevent concurrent operations
+    pub schedule: ReleaseSchedule, // how locked funds are released over time
+    pub withdrawn_amount: u64, // total amount already released to the owner
+    pub seed_timestamp: i64, // unlock timestamp at creation, frozen for PDA derivation
+    pub lock_id: Option<u64>, // caller-chosen id for id-based PDAs, None for legacy timestamp PDAs
+    pub beneficiary: Pubkey, // the only key allowed to withdraw, may differ from the funding owner
+    pub revocable: bool, // owner may revoke and reclaim unvested funds, fixed at initialize
+    pub original_owner: Pubkey, // creator key embedded in the PDA seeds, never changes
+    pub pending_owner: Option<Pubkey>, // proposed new owner awaiting acceptance
+    pub multisig: Option<MultisigConfig>, // M-of-N approval for withdrawals, closures and ownership changes
+    pub guardians: Option<GuardianSet>, // social recovery for a lost owner key
+    pub pending_recovery: Option<PendingRecovery>, // guardian-initiated owner rotation awaiting its delay
+    pub early_unlock: Option<EarlyUnlockPenalty>, // soft lock terms, None keeps the unlock time absolute
+    pub unlock_condition: UnlockCondition, // clock field checked by is_unlocked
+    pub hashlock: Option<HashLock>, // HTLC terms, unlock_timestamp doubles as the claim deadline
+    pub inheritance: Option<Inheritance>, // dead-man's switch: heirs claim once the owner stops checking in
+    pub basket_assets: Vec<BasketAsset>, // per-mint vaults of a basket lock, SOL stays in sol_balance
+    pub mint: Pubkey, // locked mint of a token lock, fixed at creation
 }
 
 impl TimeLockAccount {
@@ -34,7 +51,86 @@ impl TimeLockAccount {
         1 +  // is_initialized: bool
         8 +  // sol_balance: u64
         33 + // spl_token_account: Option<Pubkey> (1 + 32)
-        1;   // is_processing: bool - reentrancy guard
+        1 +  // is_processing: bool - reentrancy guard
+        ReleaseSchedule::MAX_SPACE + // schedule: ReleaseSchedule
+        8 +  // withdrawn_amount: u64
+        8 +  // seed_timestamp: i64
+        9 +  // lock_id: Option<u64> (1 + 8)
+        32 + // beneficiary: Pubkey
+        1 +  // revocable: bool
+        32 + // original_owner: Pubkey
+        33 + // pending_owner: Option<Pubkey> (1 + 32)
+        1 + MultisigConfig::MAX_SPACE + // multisig: Option<MultisigConfig>
+        1 + GuardianSet::MAX_SPACE +    // guardians: Option<GuardianSet>
+        1 + PendingRecovery::SPACE +    // pending_recovery: Option<PendingRecovery>
+        1 + EarlyUnlockPenalty::SPACE + // early_unlock: Option<EarlyUnlockPenalty>
+        UnlockCondition::SPACE +        // unlock_condition: UnlockCondition
+        1 + HashLock::SPACE +           // hashlock: Option<HashLock>
+        1 + Inheritance::MAX_SPACE +    // inheritance: Option<Inheritance>
+        4 + BasketAsset::SPACE * BasketAsset::MAX_ASSETS + // basket_assets: Vec<BasketAsset>
+        32;  // mint: Pubkey
+    
+    // Legacy scheme: [b"time_lock", owner, unlock_timestamp at creation]
+    pub const SEED_PREFIX: &'static [u8] = b"time_lock";
+    // Id scheme: [b"time_lock_id", owner, lock_id]
+    pub const LOCK_ID_SEED_PREFIX: &'static [u8] = b"time_lock_id";
+    
+    // 🔑 PDA seed prefix for the scheme this account was created with
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        match self.lock_id {
+            Some(_) => Self::LOCK_ID_SEED_PREFIX,
+            None => Self::SEED_PREFIX,
+        }
+    }
+    
+    // 🔑 Per-owner PDA seed: lock_id for id-based accounts, creation timestamp for legacy ones
+    pub fn seed_id(&self) -> [u8; 8] {
+        match self.lock_id {
+            Some(lock_id) => lock_id.to_le_bytes(),
+            None => self.seed_timestamp.to_le_bytes(),
+        }
+    }
+    
+    // 🔄 Hand ownership to `new_owner`, returns true if withdrawal rights moved along
+    // Withdrawal rights follow the owner only when the owner was also the beneficiary
+    pub fn rotate_owner(&mut self, new_owner: Pubkey) -> bool {
+        let beneficiary_moved = self.beneficiary == self.owner;
+        if beneficiary_moved {
+            self.beneficiary = new_owner;
+        }
+        self.owner = new_owner;
+        self.pending_owner = None;
+        beneficiary_moved
+    }
+    
+    // 🔏 Require multisig approval when the lock has a multisig configured
+    // The primary signer and any signing remaining accounts count towards the threshold
+    pub fn require_multisig_approval(&self, primary_signer: &Pubkey, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let Some(multisig) = &self.multisig else {
+            return Ok(());
+        };
+        
+        let approvals = count_signed(&multisig.signers, primary_signer, remaining_accounts);
+        if approvals < multisig.threshold as usize {
+            msg!("❌ MULTISIG: {} of {} required approvals", approvals, multisig.threshold);
+            return Err(TimeLockError::InsufficientApprovals.into());
+        }
+        
+        Ok(())
+    }
+    
+    // 🛡️ Recovery drops the multisig, so it must take at least as many guardians as the multisig takes signers
+    pub fn validate_recovery_threshold(&self) -> Result<()> {
+        if let (Some(guardians), Some(multisig)) = (&self.guardians, &self.multisig) {
+            require!(guardians.threshold >= multisig.threshold, TimeLockError::InvalidGuardianConfig);
+        }
+        Ok(())
+    }
+    
+    // 🔏 Multisig approval for withdrawals, grants included since their beneficiary co-signed the signer set
+    pub fn require_withdrawal_approval(&self, beneficiary: &Pubkey, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        self.require_multisig_approval(beneficiary, remaining_accounts)
+    }
     
     // 🔐 Start critical operation with reentrancy protection
     pub fn start_operation(&mut self) -> Result<()> {
@@ -66,16 +162,26 @@ impl TimeLockAccount {
     pub fn is_unlocked(&self) -> Result<bool> {
         let clock = Clock::get()?;
         let current_time = clock.unix_timestamp;
-        let is_expired = current_time >= self.unlock_timestamp;
+        let is_expired = self.unlock_condition.is_met(&clock, self.unlock_timestamp);
         
         // 📊 Detailed logging for frontend debugging
-        msg!("🕒 Time Check - Current: {}, Unlock: {}, Expired: {}", 
-             current_time, self.unlock_timestamp, is_expired);
-        
-        if !is_expired {
-            let time_remaining = self.unlock_timestamp - current_time;
-            msg!("⏱️ Time remaining: {} seconds ({} hours)", 
-                 time_remaining, time_remaining / 3600);
+        match self.unlock_condition {
+            UnlockCondition::Timestamp => {
+                msg!("🕒 Time Check - Current: {}, Unlock: {}, Expired: {}", 
+                     current_time, self.unlock_timestamp, is_expired);
+                
+                if !is_expired {
+                    let time_remaining = self.unlock_timestamp - current_time;
+                    msg!("⏱️ Time remaining: {} seconds ({} hours)", 
+                         time_remaining, time_remaining / 3600);
+                }
+            },
+            UnlockCondition::Slot(slot) => {
+                msg!("🎰 Slot Check - Current: {}, Unlock: {}, Expired: {}", clock.slot, slot, is_expired);
+            },
+            UnlockCondition::Epoch(epoch) => {
+                msg!("🌀 Epoch Check - Current: {}, Unlock: {}, Expired: {}", clock.epoch, epoch, is_expired);
+            },
         }
         
         Ok(is_expired)
@@ -108,6 +214,690 @@ impl TimeLockAccount {
              self.sol_balance);
         Ok(())
     }
+    
+    // 💰 Validate a partial SOL withdrawal of `amount` lamports
+    pub fn validate_partial_sol_withdrawal(&self, amount: u64) -> Result<()> {
+        require!(amount > 0, TimeLockError::InvalidAmount);
+        self.validate_sol_withdrawal()?;
+        
+        if amount > self.sol_balance {
+            msg!("❌ VALIDATION_FAILED: Requested {} lamports exceeds balance of {} lamports", 
+                 amount, self.sol_balance);
+            return Err(TimeLockError::InsufficientBalance.into());
+        }
+        
+        Ok(())
+    }
+    
+    // 🪙 Validate a token withdrawal of `amount` tokens
+    pub fn validate_token_withdrawal(&self, amount: u64) -> Result<()> {
+        msg!("🔍 Validating token withdrawal...");
+        
+        if !self.is_initialized {
+            msg!("❌ VALIDATION_FAILED: Account not initialized");
+            return Err(TimeLockError::NotInitialized.into());
+        }
+        
+        require!(amount > 0, TimeLockError::InvalidAmount);
+        
+        if !self.is_unlocked()? {
+            msg!("❌ VALIDATION_FAILED: Time lock not expired");
+            return Err(TimeLockError::TimeLockNotExpired.into());
+        }
+        
+        if amount > self.amount {
+            msg!("❌ VALIDATION_FAILED: Requested {} tokens exceeds balance of {} tokens", 
+                 amount, self.amount);
+            return Err(TimeLockError::InsufficientBalance.into());
+        }
+        
+        msg!("✅ VALIDATION_SUCCESS: Ready for token withdrawal - Amount: {}", amount);
+        Ok(())
+    }
+    
+    // 🔐 Validate a hashlock claim: before the deadline, with the matching preimage
+    pub fn validate_preimage_claim(&self, preimage: &[u8], current_time: i64) -> Result<()> {
+        require!(self.is_initialized, TimeLockError::NotInitialized);
+        
+        let hashlock = self.hashlock.as_ref().ok_or(TimeLockError::NotHashLocked)?;
+        require!(current_time < self.unlock_timestamp, TimeLockError::HashlockExpired);
+        
+        if !hashlock.matches(preimage) {
+            msg!("❌ VALIDATION_FAILED: Preimage does not match hashlock");
+            return Err(TimeLockError::InvalidPreimage.into());
+        }
+        
+        Ok(())
+    }
+    
+    // 📈 Total amount released by the schedule at `current_time`
+    // Covers everything ever deposited, including what was already withdrawn
+    pub fn vested_amount(&self, current_time: i64) -> Result<u64> {
+        let total = self.amount
+            .checked_add(self.withdrawn_amount)
+            .ok_or(TimeLockError::ArithmeticOverflow)?;
+        
+        match &self.schedule {
+            ReleaseSchedule::Cliff => {
+                // Slot and epoch conditions are read from the Clock sysvar instead of `current_time`
+                let reached = match self.unlock_condition {
+                    UnlockCondition::Timestamp => current_time >= self.unlock_timestamp,
+                    _ => self.unlock_condition.is_met(&Clock::get()?, self.unlock_timestamp),
+                };
+                Ok(if reached { total } else { 0 })
+            },
+            &ReleaseSchedule::Linear { start_ts, cliff_ts, end_ts } => {
+                if current_time < cliff_ts {
+                    return Ok(0);
+                }
+                if current_time >= end_ts {
+                    return Ok(total);
+                }
+                
+                let elapsed = (current_time - start_ts) as u128;
+                let duration = (end_ts - start_ts) as u128;
+                let vested = (total as u128)
+                    .checked_mul(elapsed)
+                    .ok_or(TimeLockError::ArithmeticOverflow)?
+                    / duration;
+                
+                Ok(vested as u64)
+            },
+            ReleaseSchedule::Tranches(tranches) => {
+                // The final tranche releases whatever is left, including surplus deposits
+                if current_time >= self.unlock_timestamp {
+                    return Ok(total);
+                }
+                
+                let matured = tranches
+                    .iter()
+                    .filter(|tranche| current_time >= tranche.timestamp)
+                    .try_fold(0u64, |sum, tranche| sum.checked_add(tranche.amount))
+                    .ok_or(TimeLockError::ArithmeticOverflow)?;
+                
+                Ok(matured.min(total))
+            },
+        }
+    }
+    
+    // 🎁 Amount the owner can claim right now
+    pub fn claimable_amount(&self, current_time: i64) -> Result<u64> {
+        Ok(self.vested_amount(current_time)?.saturating_sub(self.withdrawn_amount))
+    }
+    
+    // ⏭️ Next discrete release after `current_time` as (timestamp, amount)
+    // Linear schedules release continuously and report no discrete next step
+    pub fn next_release(&self, current_time: i64) -> Option<(i64, u64)> {
+        match &self.schedule {
+            ReleaseSchedule::Cliff
+                if self.unlock_condition == UnlockCondition::Timestamp && current_time < self.unlock_timestamp =>
+            {
+                Some((self.unlock_timestamp, self.amount))
+            },
+            ReleaseSchedule::Tranches(tranches) => tranches
+                .iter()
+                .find(|tranche| tranche.timestamp > current_time)
+                .map(|tranche| (tranche.timestamp, tranche.amount)),
+            _ => None,
+        }
+    }
+    
+    // 💸 Penalty owed for leaving with `amount` at `current_time`, as (penalty_bps, penalty)
+    pub fn early_withdrawal_penalty(&self, amount: u64, current_time: i64) -> Result<(u16, u64)> {
+        let terms = self.early_unlock.as_ref().ok_or(TimeLockError::EarlyUnlockNotAllowed)?;
+        let penalty_bps = terms.penalty_bps_at(current_time, self.unlock_timestamp);
+        
+        // Rounds down, the penalty never exceeds the withdrawn amount
+        let penalty = (amount as u128)
+            .checked_mul(penalty_bps as u128)
+            .ok_or(TimeLockError::ArithmeticOverflow)?
+            / EarlyUnlockPenalty::BPS_DENOMINATOR as u128;
+        
+        Ok((penalty_bps, penalty as u64))
+    }
+}
+
+// Layout of `TimeLockAccount` before the schedule, seed and ownership fields were added
+// Only read by migrate_legacy_account, which rewrites such accounts into the current layout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyTimeLockAccount {
+    pub owner: Pubkey,
+    pub unlock_timestamp: i64,
+    pub asset_type: AssetType,
+    pub bump: u8,
+    pub amount: u64,
+    pub token_vault: Pubkey,
+    pub is_initialized: bool,
+    pub sol_balance: u64,
+    pub spl_token_account: Option<Pubkey>,
+    pub is_processing: bool,
+}
+
+impl LegacyTimeLockAccount {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // owner: Pubkey
+        8 +  // unlock_timestamp: i64
+        1 +  // asset_type: AssetType enum
+        1 +  // bump: u8
+        8 +  // amount: u64
+        32 + // token_vault: Pubkey
+        1 +  // is_initialized: bool
+        8 +  // sol_balance: u64
+        33 + // spl_token_account: Option<Pubkey> (1 + 32)
+        1;   // is_processing: bool
+    
+    // 🔄 Current layout with defaults for every field the legacy account lacks
+    // Legacy locks could not move their unlock time or owner, so both still match the PDA seeds
+    pub fn migrate(self, mint: Pubkey) -> TimeLockAccount {
+        TimeLockAccount {
+            owner: self.owner,
+            unlock_timestamp: self.unlock_timestamp,
+            asset_type: self.asset_type,
+            bump: self.bump,
+            amount: self.amount,
+            token_vault: self.token_vault,
+            is_initialized: self.is_initialized,
+            sol_balance: self.sol_balance,
+            spl_token_account: self.spl_token_account,
+            is_processing: self.is_processing,
+            schedule: ReleaseSchedule::Cliff,
+            withdrawn_amount: 0,
+            seed_timestamp: self.unlock_timestamp,
+            lock_id: None,
+            beneficiary: self.owner,
+            revocable: false,
+            original_owner: self.owner,
+            pending_owner: None,
+            multisig: None,
+            guardians: None,
+            pending_recovery: None,
+            early_unlock: None,
+            unlock_condition: UnlockCondition::Timestamp,
+            hashlock: None,
+            inheritance: None,
+            basket_assets: Vec::new(),
+            mint,
+        }
+    }
+}
+
+// Program-wide policy, a singleton PDA at [b"config"] managed by `admin`
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,                    // receives protocol fees
+    pub force_close_grace_seconds: i64,      // anyone may force close a timestamp lock this long after it unlocked
+    pub force_close_max_bounty_lamports: u64, // cap on the cranker's reward, paid out of rent and never out of principal
+    pub min_lock_duration: i64,              // bounds on unlock_timestamp - now for timestamp locks
+    pub max_lock_duration: i64,
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub flat_fee_lamports: u64,              // charged on top of the bps fee on each fee-bearing operation
+    pub paused: bool,                        // blocks new locks and deposits, never withdrawals
+    pub bump: u8,
+}
+
+impl Config {
+    pub const INIT_SPACE: usize = 8 + // discriminator
+        32 + // admin: Pubkey
+        32 + // treasury: Pubkey
+        8 +  // force_close_grace_seconds: i64
+        8 +  // force_close_max_bounty_lamports: u64
+        8 +  // min_lock_duration: i64
+        8 +  // max_lock_duration: i64
+        2 +  // deposit_fee_bps: u16
+        2 +  // withdrawal_fee_bps: u16
+        8 +  // flat_fee_lamports: u64
+        1 +  // paused: bool
+        1;   // bump: u8
+    
+    pub const SEED: &'static [u8] = b"config";
+    // Fees can never take more than 10% of an amount
+    pub const MAX_FEE_BPS: u16 = 1_000;
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+    // Flat fees are capped at 0.1 SOL per operation
+    pub const MAX_FLAT_FEE_LAMPORTS: u64 = 100_000_000;
+    
+    // ⚙️ Replace every policy value, params must already be validated
+    pub fn apply(&mut self, params: &ConfigParams) {
+        self.treasury = params.treasury;
+        self.force_close_grace_seconds = params.force_close_grace_seconds;
+        self.force_close_max_bounty_lamports = params.force_close_max_bounty_lamports;
+        self.min_lock_duration = params.min_lock_duration;
+        self.max_lock_duration = params.max_lock_duration;
+        self.deposit_fee_bps = params.deposit_fee_bps;
+        self.withdrawal_fee_bps = params.withdrawal_fee_bps;
+        self.flat_fee_lamports = params.flat_fee_lamports;
+    }
+    
+    // 💸 Basis-point fee on `amount`, rounded down so the payer is never overcharged
+    pub fn bps_fee(amount: u64, fee_bps: u16) -> u64 {
+        (amount as u128 * fee_bps as u128 / Self::BPS_DENOMINATOR as u128) as u64
+    }
+    
+    // 💸 Lamports charged on a SOL deposit: the bps fee taken from `amount` plus the flat fee on top
+    pub fn sol_deposit_fees(&self, amount: u64) -> Result<(u64, u64)> {
+        let fee_amount = Self::bps_fee(amount, self.deposit_fee_bps);
+        let total = fee_amount
+            .checked_add(self.flat_fee_lamports)
+            .ok_or(TimeLockError::ArithmeticOverflow)?;
+        Ok((fee_amount, total))
+    }
+    
+    // ⏳ Timestamp locks must unlock within [min_lock_duration, max_lock_duration] from now
+    pub fn validate_lock_duration(&self, unlock_timestamp: i64, current_timestamp: i64) -> Result<()> {
+        let duration = unlock_timestamp.saturating_sub(current_timestamp);
+        if duration < self.min_lock_duration || duration > self.max_lock_duration {
+            msg!("❌ LOCK DURATION: {} seconds, allowed {}..={}", duration, self.min_lock_duration, self.max_lock_duration);
+            return Err(TimeLockError::LockDurationOutOfRange.into());
+        }
+        Ok(())
+    }
+    
+    // 🧹 Latest moment an expired lock is left alone before anyone may force-close it
+    pub fn force_close_deadline(&self, unlock_timestamp: i64) -> Result<i64> {
+        unlock_timestamp
+            .checked_add(self.force_close_grace_seconds)
+            .ok_or_else(|| error!(TimeLockError::ArithmeticOverflow))
+    }
+}
+
+// Admin-supplied values for `Config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ConfigParams {
+    pub treasury: Pubkey,
+    pub force_close_grace_seconds: i64,
+    pub force_close_max_bounty_lamports: u64,
+    pub min_lock_duration: i64,
+    pub max_lock_duration: i64,
+    pub deposit_fee_bps: u16,
+    pub withdrawal_fee_bps: u16,
+    pub flat_fee_lamports: u64,
+}
+
+impl ConfigParams {
+    // 🔍 Non-negative durations with min <= max, bounded fees and a treasury whenever a fee is set
+    pub fn validate(&self) -> Result<()> {
+        let charges_fees = self.deposit_fee_bps > 0 || self.withdrawal_fee_bps > 0 || self.flat_fee_lamports > 0;
+        require!(
+            self.force_close_grace_seconds >= 0
+                && self.min_lock_duration >= 0
+                && self.min_lock_duration <= self.max_lock_duration
+                && self.deposit_fee_bps <= Config::MAX_FEE_BPS
+                && self.withdrawal_fee_bps <= Config::MAX_FEE_BPS
+                && self.flat_fee_lamports <= Config::MAX_FLAT_FEE_LAMPORTS
+                && (!charges_fees || self.treasury != Pubkey::default()),
+            TimeLockError::InvalidConfig
+        );
+        Ok(())
+    }
+}
+
+// Soft lock terms: leaving before unlock_timestamp costs a penalty paid to `recipient`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct EarlyUnlockPenalty {
+    pub penalty_bps: u16,
+    pub linear_decay: bool, // penalty shrinks linearly from start_ts to zero at unlock
+    pub recipient: Pubkey,
+    pub start_ts: i64,
+}
+
+impl EarlyUnlockPenalty {
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+    
+    pub const SPACE: usize = 2 + // penalty_bps: u16
+        1 +  // linear_decay: bool
+        32 + // recipient: Pubkey
+        8;   // start_ts: i64
+    
+    // 🔍 Validate penalty terms
+    pub fn validate(&self, unlock_timestamp: i64) -> Result<()> {
+        require!(
+            self.penalty_bps <= Self::BPS_DENOMINATOR && self.recipient != Pubkey::default(),
+            TimeLockError::InvalidPenalty
+        );
+        require!(self.start_ts < unlock_timestamp, TimeLockError::InvalidUnlockTime);
+        Ok(())
+    }
+    
+    // 📉 Penalty in basis points at `current_time`, zero once unlocked
+    pub fn penalty_bps_at(&self, current_time: i64, unlock_timestamp: i64) -> u16 {
+        if current_time >= unlock_timestamp {
+            return 0;
+        }
+        if !self.linear_decay || current_time <= self.start_ts {
+            return self.penalty_bps;
+        }
+        
+        // Scale by the share of the lock period still remaining
+        let remaining = (unlock_timestamp - current_time) as i128;
+        let duration = (unlock_timestamp - self.start_ts) as i128;
+        (self.penalty_bps as i128 * remaining / duration) as u16
+    }
+}
+
+// M-of-N signer set guarding a lock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct MultisigConfig {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl MultisigConfig {
+    pub const MAX_SIGNERS: usize = 7;
+    
+    pub const MAX_SPACE: usize = 4 + // Vec length prefix
+        32 * Self::MAX_SIGNERS + // signers: Vec<Pubkey>
+        1;  // threshold: u8
+    
+    // 🔍 Validate signer set and threshold
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            is_valid_threshold_set(&self.signers, self.threshold, Self::MAX_SIGNERS),
+            TimeLockError::InvalidMultisigConfig
+        );
+        Ok(())
+    }
+}
+
+// Guardians that can jointly rotate a lost owner key after a delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct GuardianSet {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+impl GuardianSet {
+    pub const MAX_GUARDIANS: usize = 5;
+    // The current owner has this long to cancel a recovery before it can complete
+    pub const RECOVERY_DELAY_SECONDS: i64 = 86400 * 7; // 7 days
+    
+    pub const MAX_SPACE: usize = 4 + // Vec length prefix
+        32 * Self::MAX_GUARDIANS + // guardians: Vec<Pubkey>
+        1;  // threshold: u8
+    
+    // 🔍 Validate guardian set and threshold
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            is_valid_threshold_set(&self.guardians, self.threshold, Self::MAX_GUARDIANS),
+            TimeLockError::InvalidGuardianConfig
+        );
+        Ok(())
+    }
+    
+    // 🛡️ Require the guardian threshold among the primary signer and signing remaining accounts
+    pub fn require_approval(&self, primary_signer: &Pubkey, remaining_accounts: &[AccountInfo]) -> Result<()> {
+        let approvals = count_signed(&self.guardians, primary_signer, remaining_accounts);
+        if approvals < self.threshold as usize {
+            msg!("❌ GUARDIANS: {} of {} required approvals", approvals, self.threshold);
+            return Err(TimeLockError::InsufficientApprovals.into());
+        }
+        Ok(())
+    }
+}
+
+// Owner rotation started by guardians, executable once the delay has passed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PendingRecovery {
+    pub new_owner: Pubkey,
+    pub initiated_at: i64,
+}
+
+impl PendingRecovery {
+    pub const SPACE: usize = 32 + // new_owner: Pubkey
+        8;  // initiated_at: i64
+    
+    pub fn executable_at(&self) -> i64 {
+        self.initiated_at.saturating_add(GuardianSet::RECOVERY_DELAY_SECONDS)
+    }
+}
+
+// Number of `keys` that signed, as the primary signer or as signing remaining accounts
+fn count_signed(keys: &[Pubkey], primary_signer: &Pubkey, remaining_accounts: &[AccountInfo]) -> usize {
+    keys.iter()
+        .filter(|key| {
+            *key == primary_signer
+                || remaining_accounts.iter().any(|account| account.is_signer && account.key == *key)
+        })
+        .count()
+}
+
+// Keys must be non-empty, bounded and unique, with 1 <= threshold <= keys
+fn is_valid_threshold_set(keys: &[Pubkey], threshold: u8, max_keys: usize) -> bool {
+    // Duplicate keys would let one signer count twice
+    let unique = keys
+        .iter()
+        .enumerate()
+        .all(|(index, key)| !keys[index + 1..].contains(key));
+    
+    !keys.is_empty()
+        && keys.len() <= max_keys
+        && threshold > 0
+        && threshold as usize <= keys.len()
+        && unique
+}
+
+// Hash-time-lock terms: revealing the sha256 preimage before the deadline pays `counterparty`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct HashLock {
+    pub hash: [u8; 32],
+    pub counterparty: Pubkey,
+}
+
+impl HashLock {
+    pub const SPACE: usize = 32 + // hash: [u8; 32]
+        32;  // counterparty: Pubkey
+    
+    pub fn matches(&self, preimage: &[u8]) -> bool {
+        anchor_lang::solana_program::hash::hash(preimage).to_bytes() == self.hash
+    }
+}
+
+// Dead-man's switch: the owner must call heartbeat every `heartbeat_interval` seconds,
+// otherwise the heirs can split the funds by basis points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Inheritance {
+    pub heartbeat_interval: i64,
+    pub last_heartbeat: i64,
+    pub heirs: Vec<Heir>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Heir {
+    pub heir: Pubkey,
+    pub bps: u16,
+}
+
+impl Heir {
+    pub const SPACE: usize = 32 + // heir: Pubkey
+        2;   // bps: u16
+}
+
+impl Inheritance {
+    pub const MAX_HEIRS: usize = 5;
+    pub const BPS_TOTAL: u16 = 10_000;
+    
+    pub const MAX_SPACE: usize = 8 + // heartbeat_interval: i64
+        8 + // last_heartbeat: i64
+        4 + Heir::SPACE * Self::MAX_HEIRS; // heirs: Vec<Heir>
+    
+    // 🔍 Heirs must be unique, bounded and split exactly 100%
+    pub fn validate(&self) -> Result<()> {
+        require!(self.heartbeat_interval > 0, TimeLockError::InvalidHeartbeatInterval);
+        require!(
+            !self.heirs.is_empty() && self.heirs.len() <= Self::MAX_HEIRS,
+            TimeLockError::InvalidHeirs
+        );
+        
+        let mut total_bps: u32 = 0;
+        for (index, heir) in self.heirs.iter().enumerate() {
+            require!(heir.bps > 0, TimeLockError::InvalidHeirs);
+            require!(
+                !self.heirs[index + 1..].iter().any(|other| other.heir == heir.heir),
+                TimeLockError::InvalidHeirs
+            );
+            total_bps += heir.bps as u32;
+        }
+        require!(total_bps == Self::BPS_TOTAL as u32, TimeLockError::InvalidHeirs);
+        
+        Ok(())
+    }
+    
+    pub fn deadline(&self) -> i64 {
+        self.last_heartbeat.saturating_add(self.heartbeat_interval)
+    }
+    
+    pub fn has_lapsed(&self, current_time: i64) -> bool {
+        current_time > self.deadline()
+    }
+    
+    // 🧮 Split `total` by heir basis points, the last heir takes the rounding remainder
+    pub fn shares(&self, total: u64) -> Result<Vec<u64>> {
+        let mut shares = Vec::with_capacity(self.heirs.len());
+        let mut distributed: u64 = 0;
+        
+        for (index, heir) in self.heirs.iter().enumerate() {
+            let share = if index + 1 == self.heirs.len() {
+                total - distributed
+            } else {
+                ((total as u128 * heir.bps as u128) / Self::BPS_TOTAL as u128) as u64
+            };
+            distributed = distributed
+                .checked_add(share)
+                .ok_or(TimeLockError::ArithmeticOverflow)?;
+            shares.push(share);
+        }
+        
+        Ok(shares)
+    }
+}
+
+// One mint held by a basket lock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct BasketAsset {
+    pub mint: Pubkey,
+    pub vault: Pubkey, // ATA of the lock PDA for `mint`
+    pub amount: u64,
+}
+
+impl BasketAsset {
+    pub const MAX_ASSETS: usize = 5;
+    
+    pub const SPACE: usize = 32 + // mint: Pubkey
+        32 + // vault: Pubkey
+        8;   // amount: u64
+}
+
+// Clock field a lock waits on before it opens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UnlockCondition {
+    // Clock::unix_timestamp >= unlock_timestamp
+    Timestamp,
+    // Clock::slot >= the target slot
+    Slot(u64),
+    // Clock::epoch >= the target epoch, lines up with staking epochs
+    Epoch(u64),
+}
+
+impl UnlockCondition {
+    pub const SPACE: usize = 1 + // variant tag
+        8;  // slot or epoch: u64
+    
+    pub fn is_met(&self, clock: &Clock, unlock_timestamp: i64) -> bool {
+        match *self {
+            UnlockCondition::Timestamp => clock.unix_timestamp >= unlock_timestamp,
+            UnlockCondition::Slot(slot) => clock.slot >= slot,
+            UnlockCondition::Epoch(epoch) => clock.epoch >= epoch,
+        }
+    }
+    
+    // 🔍 Slot and epoch targets must still be ahead of the chain
+    pub fn validate(&self, clock: &Clock) -> Result<()> {
+        let in_future = match *self {
+            UnlockCondition::Timestamp => false,
+            UnlockCondition::Slot(slot) => slot > clock.slot,
+            UnlockCondition::Epoch(epoch) => epoch > clock.epoch,
+        };
+        require!(in_future, TimeLockError::InvalidUnlockCondition);
+        Ok(())
+    }
+}
+
+// Defines how locked funds are released over time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum ReleaseSchedule {
+    // Everything unlocks at unlock_timestamp
+    Cliff,
+    // Nothing before cliff_ts, then linear release from start_ts until end_ts
+    Linear {
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+    },
+    // Each tranche releases its amount once its timestamp is reached
+    Tranches(Vec<UnlockTranche>),
+}
+
+impl ReleaseSchedule {
+    pub const MAX_TRANCHES: usize = 12;
+    
+    // Largest variant is Tranches with a full vector
+    pub const MAX_SPACE: usize = 1 + // variant tag
+        4 + // Vec length prefix
+        UnlockTranche::SPACE * Self::MAX_TRANCHES;
+    
+    // 🔍 Validate schedule boundaries against the current time
+    pub fn validate(&self, current_time: i64) -> Result<()> {
+        match self {
+            &ReleaseSchedule::Linear { start_ts, cliff_ts, end_ts } => {
+                require!(
+                    start_ts < end_ts && start_ts <= cliff_ts && cliff_ts <= end_ts,
+                    TimeLockError::InvalidVestingSchedule
+                );
+                require!(end_ts > current_time, TimeLockError::InvalidUnlockTime);
+            },
+            ReleaseSchedule::Tranches(tranches) => {
+                require!(
+                    !tranches.is_empty() && tranches.len() <= Self::MAX_TRANCHES,
+                    TimeLockError::InvalidTrancheSchedule
+                );
+                require!(
+                    tranches.iter().all(|tranche| tranche.amount > 0),
+                    TimeLockError::InvalidTrancheSchedule
+                );
+                require!(
+                    tranches.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp),
+                    TimeLockError::InvalidTrancheSchedule
+                );
+                require!(tranches[0].timestamp > current_time, TimeLockError::InvalidUnlockTime);
+            },
+            ReleaseSchedule::Cliff => {},
+        }
+        Ok(())
+    }
+    
+    // Timestamp of the last tranche, used as unlock_timestamp and for PDA derivation
+    pub fn final_tranche_timestamp(tranches: &[UnlockTranche]) -> i64 {
+        tranches.last().map(|tranche| tranche.timestamp).unwrap_or_default()
+    }
+    
+    // PDA seed bytes of the final tranche timestamp
+    // A plain call keeps the IDL builder from inlining `tranches` as a constant seed
+    pub fn final_tranche_seed(tranches: &[UnlockTranche]) -> [u8; 8] {
+        Self::final_tranche_timestamp(tranches).to_le_bytes()
+    }
+}
+
+// A single (timestamp, amount) step of a tranche schedule
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct UnlockTranche {
+    pub timestamp: i64,
+    pub amount: u64,
+}
+
+impl UnlockTranche {
+    pub const SPACE: usize = 8 + // timestamp: i64
+        8;  // amount: u64
 }
 
 // Enum to define the type of asset being locked
@@ -115,4 +905,347 @@ impl TimeLockAccount {
 pub enum AssetType {
     Sol, 
     Token,
-}
\ No newline at end of file
+    Basket, // native SOL plus several mints, each in its own vault owned by the lock PDA
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+    
+    // All-zero bytes decode to the first variant, zero, None or empty for every field,
+    // so the fixture keeps compiling as TimeLockAccount grows
+    fn test_lock(schedule: ReleaseSchedule, amount: u64, withdrawn_amount: u64, unlock_timestamp: i64) -> TimeLockAccount {
+        let zeroed = [0u8; TimeLockAccount::INIT_SPACE];
+        let mut lock: TimeLockAccount = AnchorDeserialize::deserialize(&mut &zeroed[..]).unwrap();
+        lock.is_initialized = true;
+        lock.schedule = schedule;
+        lock.amount = amount;
+        lock.sol_balance = amount;
+        lock.withdrawn_amount = withdrawn_amount;
+        lock.unlock_timestamp = unlock_timestamp;
+        lock
+    }
+    
+    fn linear(start_ts: i64, cliff_ts: i64, end_ts: i64) -> ReleaseSchedule {
+        ReleaseSchedule::Linear { start_ts, cliff_ts, end_ts }
+    }
+    
+    #[test]
+    fn cliff_releases_everything_at_unlock() {
+        let lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        
+        assert_eq!(lock.vested_amount(499).unwrap(), 0);
+        assert_eq!(lock.vested_amount(500).unwrap(), 1_000);
+    }
+    
+    #[test]
+    fn linear_vests_nothing_before_the_cliff() {
+        let lock = test_lock(linear(100, 200, 1_100), 1_000, 0, 1_100);
+        
+        assert_eq!(lock.vested_amount(50).unwrap(), 0);
+        assert_eq!(lock.vested_amount(199).unwrap(), 0);
+    }
+    
+    #[test]
+    fn linear_vests_from_start_once_the_cliff_passes() {
+        let lock = test_lock(linear(100, 200, 1_100), 1_000, 0, 1_100);
+        
+        // Time between start and cliff counts towards the release
+        assert_eq!(lock.vested_amount(200).unwrap(), 100);
+        assert_eq!(lock.vested_amount(600).unwrap(), 500);
+        assert_eq!(lock.vested_amount(1_099).unwrap(), 999);
+        assert_eq!(lock.vested_amount(1_100).unwrap(), 1_000);
+        assert_eq!(lock.vested_amount(i64::MAX).unwrap(), 1_000);
+    }
+    
+    #[test]
+    fn linear_counts_withdrawn_funds_towards_the_total() {
+        let lock = test_lock(linear(100, 100, 1_100), 700, 300, 1_100);
+        
+        assert_eq!(lock.vested_amount(600).unwrap(), 500);
+        assert_eq!(lock.claimable_amount(600).unwrap(), 200);
+        assert_eq!(lock.claimable_amount(1_100).unwrap(), 700);
+    }
+    
+    #[test]
+    fn claimable_never_goes_negative() {
+        let lock = test_lock(linear(100, 100, 1_100), 700, 300, 1_100);
+        
+        // Only 200 vested but 300 already left the lock
+        assert_eq!(lock.claimable_amount(300).unwrap(), 0);
+    }
+    
+    #[test]
+    fn linear_handles_amounts_near_u64_max() {
+        let lock = test_lock(linear(0, 0, 4), u64::MAX, 0, 4);
+        
+        assert_eq!(lock.vested_amount(2).unwrap(), u64::MAX / 2);
+    }
+    
+    #[test]
+    fn vested_amount_rejects_overflowing_totals() {
+        let lock = test_lock(ReleaseSchedule::Cliff, u64::MAX, 1, 500);
+        
+        assert_eq!(lock.vested_amount(500).unwrap_err(), TimeLockError::ArithmeticOverflow.into());
+    }
+    
+    #[test]
+    fn tranches_release_each_step_once_reached() {
+        let tranches = vec![
+            UnlockTranche { timestamp: 100, amount: 250 },
+            UnlockTranche { timestamp: 200, amount: 250 },
+            UnlockTranche { timestamp: 300, amount: 500 },
+        ];
+        let lock = test_lock(ReleaseSchedule::Tranches(tranches), 1_000, 0, 300);
+        
+        assert_eq!(lock.vested_amount(99).unwrap(), 0);
+        assert_eq!(lock.vested_amount(100).unwrap(), 250);
+        assert_eq!(lock.vested_amount(250).unwrap(), 500);
+        assert_eq!(lock.vested_amount(300).unwrap(), 1_000);
+    }
+    
+    #[test]
+    fn final_tranche_releases_surplus_deposits() {
+        let tranches = vec![
+            UnlockTranche { timestamp: 100, amount: 400 },
+            UnlockTranche { timestamp: 200, amount: 600 },
+        ];
+        let lock = test_lock(ReleaseSchedule::Tranches(tranches), 1_500, 0, 200);
+        
+        assert_eq!(lock.vested_amount(150).unwrap(), 400);
+        assert_eq!(lock.vested_amount(200).unwrap(), 1_500);
+    }
+    
+    #[test]
+    fn tranches_never_vest_more_than_was_deposited() {
+        let tranches = vec![
+            UnlockTranche { timestamp: 100, amount: 800 },
+            UnlockTranche { timestamp: 200, amount: 200 },
+        ];
+        let lock = test_lock(ReleaseSchedule::Tranches(tranches), 500, 0, 200);
+        
+        assert_eq!(lock.vested_amount(100).unwrap(), 500);
+        assert_eq!(lock.claimable_amount(100).unwrap(), 500);
+    }
+    
+    #[test]
+    fn final_tranche_seed_matches_the_last_timestamp() {
+        let tranches = vec![
+            UnlockTranche { timestamp: 100, amount: 1 },
+            UnlockTranche { timestamp: 200, amount: 1 },
+        ];
+        
+        assert_eq!(ReleaseSchedule::final_tranche_timestamp(&tranches), 200);
+        assert_eq!(ReleaseSchedule::final_tranche_seed(&tranches), 200i64.to_le_bytes());
+    }
+    
+    #[test]
+    fn preimage_claim_succeeds_before_the_deadline() {
+        let mut lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        lock.hashlock = Some(HashLock { hash: hash(b"secret").to_bytes(), counterparty: Pubkey::new_unique() });
+        
+        assert!(lock.validate_preimage_claim(b"secret", 499).is_ok());
+    }
+    
+    #[test]
+    fn preimage_claim_rejects_a_wrong_preimage() {
+        let mut lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        lock.hashlock = Some(HashLock { hash: hash(b"secret").to_bytes(), counterparty: Pubkey::new_unique() });
+        
+        assert_eq!(lock.validate_preimage_claim(b"Secret", 100).unwrap_err(), TimeLockError::InvalidPreimage.into());
+        assert_eq!(lock.validate_preimage_claim(b"", 100).unwrap_err(), TimeLockError::InvalidPreimage.into());
+    }
+    
+    #[test]
+    fn preimage_claim_closes_at_the_deadline() {
+        let mut lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        lock.hashlock = Some(HashLock { hash: hash(b"secret").to_bytes(), counterparty: Pubkey::new_unique() });
+        
+        // From the deadline on the funds belong to the refund path
+        assert_eq!(lock.validate_preimage_claim(b"secret", 500).unwrap_err(), TimeLockError::HashlockExpired.into());
+        assert_eq!(lock.validate_preimage_claim(b"secret", 501).unwrap_err(), TimeLockError::HashlockExpired.into());
+    }
+    
+    #[test]
+    fn preimage_claim_requires_a_hashlock() {
+        let lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        
+        assert_eq!(lock.validate_preimage_claim(b"secret", 100).unwrap_err(), TimeLockError::NotHashLocked.into());
+    }
+    
+    #[test]
+    fn shares_split_by_heir_bps() {
+        let inheritance = Inheritance {
+            heartbeat_interval: 86_400,
+            last_heartbeat: 1_000,
+            heirs: [5_000, 3_000, 2_000].map(|bps| Heir { heir: Pubkey::new_unique(), bps }).to_vec(),
+        };
+        
+        assert_eq!(inheritance.shares(1_000).unwrap(), vec![500, 300, 200]);
+    }
+    
+    #[test]
+    fn last_heir_takes_the_rounding_remainder() {
+        let inheritance = Inheritance {
+            heartbeat_interval: 86_400,
+            last_heartbeat: 1_000,
+            heirs: [3_333, 3_333, 3_334].map(|bps| Heir { heir: Pubkey::new_unique(), bps }).to_vec(),
+        };
+        let shares = inheritance.shares(10).unwrap();
+        
+        assert_eq!(shares, vec![3, 3, 4]);
+        assert_eq!(shares.iter().sum::<u64>(), 10);
+    }
+    
+    #[test]
+    fn shares_always_distribute_the_full_total() {
+        let inheritance = Inheritance {
+            heartbeat_interval: 86_400,
+            last_heartbeat: 1_000,
+            heirs: [1, 4_999, 5_000].map(|bps| Heir { heir: Pubkey::new_unique(), bps }).to_vec(),
+        };
+        
+        for total in [0, 1, 7, 9_999, 123_456_789, u64::MAX] {
+            let shares = inheritance.shares(total).unwrap();
+            
+            assert_eq!(shares.iter().map(|&share| share as u128).sum::<u128>(), total as u128);
+        }
+    }
+    
+    #[test]
+    fn heartbeat_lapses_only_after_the_interval() {
+        let inheritance = Inheritance {
+            heartbeat_interval: 86_400,
+            last_heartbeat: 1_000,
+            heirs: vec![Heir { heir: Pubkey::new_unique(), bps: 10_000 }],
+        };
+        
+        assert!(!inheritance.has_lapsed(1_000 + 86_400));
+        assert!(inheritance.has_lapsed(1_000 + 86_401));
+    }
+    
+    #[test]
+    fn bps_fee_rounds_down() {
+        assert_eq!(Config::bps_fee(10_000, 25), 25);
+        assert_eq!(Config::bps_fee(399, 25), 0);
+        assert_eq!(Config::bps_fee(401, 25), 1);
+    }
+    
+    #[test]
+    fn bps_fee_is_zero_without_fee_or_amount() {
+        assert_eq!(Config::bps_fee(1_000_000, 0), 0);
+        assert_eq!(Config::bps_fee(0, Config::MAX_FEE_BPS), 0);
+    }
+    
+    #[test]
+    fn bps_fee_split_adds_back_to_the_amount() {
+        for amount in [1, 999, 1_000_001, u64::MAX] {
+            let fee = Config::bps_fee(amount, Config::MAX_FEE_BPS);
+            let net = amount - fee;
+            
+            assert_eq!(net + fee, amount);
+            assert!(fee <= amount / 10);
+        }
+    }
+    
+    #[test]
+    fn bps_fee_does_not_overflow_on_large_amounts() {
+        assert_eq!(Config::bps_fee(u64::MAX, Config::BPS_DENOMINATOR), u64::MAX);
+        assert_eq!(Config::bps_fee(u64::MAX, Config::MAX_FEE_BPS), u64::MAX / 10);
+    }
+    
+    #[test]
+    fn grant_withdrawals_need_the_multisig_threshold() {
+        let beneficiary = Pubkey::new_unique();
+        let mut lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        lock.owner = Pubkey::new_unique();
+        lock.beneficiary = beneficiary;
+        lock.multisig = Some(MultisigConfig { signers: vec![beneficiary, Pubkey::new_unique()], threshold: 2 });
+        
+        assert_eq!(lock.require_withdrawal_approval(&beneficiary, &[]).unwrap_err(), TimeLockError::InsufficientApprovals.into());
+    }
+    
+    #[test]
+    fn withdrawals_need_no_approval_without_a_multisig() {
+        let beneficiary = Pubkey::new_unique();
+        let mut lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        lock.owner = Pubkey::new_unique();
+        lock.beneficiary = beneficiary;
+        
+        assert!(lock.require_withdrawal_approval(&beneficiary, &[]).is_ok());
+    }
+    
+    fn fee_params(flat_fee_lamports: u64) -> ConfigParams {
+        ConfigParams {
+            treasury: Pubkey::new_unique(),
+            force_close_grace_seconds: 0,
+            force_close_max_bounty_lamports: 0,
+            min_lock_duration: 0,
+            max_lock_duration: 0,
+            deposit_fee_bps: Config::MAX_FEE_BPS,
+            withdrawal_fee_bps: 0,
+            flat_fee_lamports,
+        }
+    }
+    
+    fn fee_config(flat_fee_lamports: u64) -> Config {
+        let zeroed = [0u8; Config::INIT_SPACE];
+        let mut config: Config = AnchorDeserialize::deserialize(&mut &zeroed[..]).unwrap();
+        config.apply(&fee_params(flat_fee_lamports));
+        config
+    }
+    
+    #[test]
+    fn config_caps_the_flat_fee() {
+        assert!(fee_params(Config::MAX_FLAT_FEE_LAMPORTS).validate().is_ok());
+        assert_eq!(fee_params(Config::MAX_FLAT_FEE_LAMPORTS + 1).validate().unwrap_err(), TimeLockError::InvalidConfig.into());
+        assert_eq!(fee_params(u64::MAX).validate().unwrap_err(), TimeLockError::InvalidConfig.into());
+    }
+    
+    #[test]
+    fn sol_deposit_fees_add_the_flat_fee_on_top() {
+        assert_eq!(fee_config(5_000).sol_deposit_fees(1_000_000).unwrap(), (100_000, 105_000));
+        assert_eq!(fee_config(0).sol_deposit_fees(1_000_000).unwrap(), (100_000, 100_000));
+    }
+    
+    #[test]
+    fn sol_deposit_fees_reject_overflowing_totals() {
+        // Configs written before the flat fee cap may still hold an unbounded value
+        let config = fee_config(u64::MAX);
+        
+        assert_eq!(config.sol_deposit_fees(1_000_000).unwrap_err(), TimeLockError::ArithmeticOverflow.into());
+        assert_eq!(config.sol_deposit_fees(0).unwrap(), (0, u64::MAX));
+    }
+    
+    fn guarded_lock(guardian_threshold: u8, multisig_threshold: u8) -> TimeLockAccount {
+        let mut lock = test_lock(ReleaseSchedule::Cliff, 1_000, 0, 500);
+        lock.guardians = Some(GuardianSet {
+            guardians: (0..3).map(|_| Pubkey::new_unique()).collect(),
+            threshold: guardian_threshold,
+        });
+        lock.multisig = Some(MultisigConfig {
+            signers: (0..3).map(|_| Pubkey::new_unique()).collect(),
+            threshold: multisig_threshold,
+        });
+        lock
+    }
+    
+    #[test]
+    fn recovery_needs_at_least_the_multisig_threshold() {
+        assert!(guarded_lock(2, 2).validate_recovery_threshold().is_ok());
+        assert!(guarded_lock(3, 2).validate_recovery_threshold().is_ok());
+        assert_eq!(guarded_lock(1, 2).validate_recovery_threshold().unwrap_err(), TimeLockError::InvalidGuardianConfig.into());
+    }
+    
+    #[test]
+    fn recovery_threshold_is_free_without_both_sets() {
+        let mut lock = guarded_lock(1, 3);
+        lock.multisig = None;
+        assert!(lock.validate_recovery_threshold().is_ok());
+        
+        let mut lock = guarded_lock(1, 3);
+        lock.guardians = None;
+        assert!(lock.validate_recovery_threshold().is_ok());
+    }
+}
@@ -132,7 +132,7 @@ describe("Devnet Demo Test", function() {
         .depositSol(new BN(depositAmount))
         .accountsPartial({
           timeLockAccount,
-          initializer: mainWallet.publicKey,
+          depositor: mainWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -189,7 +189,7 @@ describe("Devnet Demo Test", function() {
         .depositSol(new BN(depositAmount))
         .accountsPartial({
           timeLockAccount,
-          initializer: mainWallet.publicKey,
+          depositor: mainWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -203,7 +203,7 @@ describe("Devnet Demo Test", function() {
         .withdrawSol()
         .accountsPartial({
           timeLockAccount,
-          owner: mainWallet.publicKey,
+          beneficiary: mainWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -266,7 +266,7 @@ describe("Devnet Demo Test", function() {
         .depositSol(new BN(depositAmount))
         .accountsPartial({
           timeLockAccount,
-          initializer: mainWallet.publicKey,
+          depositor: mainWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .instruction();
@@ -293,7 +293,7 @@ describe("Devnet Demo Test", function() {
         .withdrawSol()
         .accountsPartial({
           timeLockAccount,
-          owner: mainWallet.publicKey,
+          beneficiary: mainWallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .instruction();